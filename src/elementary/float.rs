@@ -1,14 +1,18 @@
 use std::cmp::Ordering;
+use std::fmt;
 use std::ops;
 
 #[derive(Debug, Copy, Clone)]
 pub struct Float(f64);
 
 impl Float {
+    pub const EPSILON: Float = Float(0.00001);
+    pub const INFINITY: Float = Float(f64::INFINITY);
+
     pub fn value(&self) -> f64 {
         self.0
     }
-    pub fn new(value: f64) -> Float {
+    pub const fn new(value: f64) -> Float {
         Float(value)
     }
 
@@ -16,14 +20,50 @@ impl Float {
         Float(self.value().sqrt())
     }
 
-    pub fn to_string(&self) -> String {
-        self.value().to_string()
+    pub fn abs(&self) -> Float {
+        Float(self.value().abs())
+    }
+
+    pub fn floor(&self) -> Float {
+        Float(self.value().floor())
+    }
+
+    pub fn min(&self, other: Float) -> Float {
+        Float(self.value().min(other.value()))
+    }
+
+    pub fn max(&self, other: Float) -> Float {
+        Float(self.value().max(other.value()))
+    }
+
+    pub fn powf(&self, exponent: Float) -> Float {
+        Float(self.value().powf(exponent.value()))
+    }
+
+    pub fn sin(&self) -> Float {
+        Float(self.value().sin())
+    }
+
+    pub fn cos(&self) -> Float {
+        Float(self.value().cos())
+    }
+
+    pub fn tan(&self) -> Float {
+        Float(self.value().tan())
+    }
+}
+
+impl fmt::Display for Float {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value())
     }
 }
 
 impl PartialEq for Float {
     fn eq(&self, other: &Self) -> bool {
-        (self.value().abs() - other.value().abs()).abs() <= f64::EPSILON
+        // the exact check lets infinities compare equal to themselves
+        self.value() == other.value()
+            || (self.value() - other.value()).abs() < Float::EPSILON.value()
     }
 }
 
@@ -88,6 +128,7 @@ impl PartialOrd for Float {
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod float_tests {
     use super::Float;
 
@@ -103,6 +144,33 @@ mod float_tests {
         assert_eq!(a != c, true);
     }
 
+    #[test]
+    fn can_be_compared_with_opposite_sign() {
+        let a = Float::new(1.0);
+        let b = Float::new(-1.0);
+
+        assert!(a != b);
+        assert!(b < a);
+    }
+
+    #[test]
+    fn can_be_compared_within_epsilon() {
+        let a = Float::new(1.0);
+        let b = Float::new(1.000001);
+        let c = Float::new(1.0001);
+
+        assert!(a == b);
+        assert!(a != c);
+    }
+
+    #[test]
+    fn can_compare_infinities() {
+        assert!(Float::INFINITY == Float::INFINITY);
+        assert!(-Float::INFINITY == -Float::INFINITY);
+        assert!(-Float::INFINITY != Float::INFINITY);
+        assert!(Float::new(1.0) < Float::INFINITY);
+    }
+
     #[test]
     fn can_be_compared_less() {
         let a = Float::new(1.0);
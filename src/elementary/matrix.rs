@@ -1,72 +1,248 @@
 use crate::elementary::float::Float;
+use crate::elementary::point::Point;
+use crate::elementary::tuple::Tuple;
+use crate::elementary::vector::Vector;
 
-#[derive(Debug)]
-pub struct Matrix {
-    height: usize,
-    width: usize,
-    data: Vec<Float>,
+#[derive(Debug, Copy, Clone)]
+pub struct Matrix<const R: usize, const C: usize> {
+    data: [[Float; C]; R],
 }
 
-impl Matrix {
-    pub fn from_vec(height: usize, width: usize, data: Vec<Float>) -> Matrix {
-        if height * width != data.len() {
+pub type Matrix2 = Matrix<2, 2>;
+pub type Matrix3 = Matrix<3, 3>;
+pub type Matrix4 = Matrix<4, 4>;
+
+impl<const R: usize, const C: usize> Matrix<R, C> {
+    pub const fn new(data: [[Float; C]; R]) -> Matrix<R, C> {
+        Matrix { data }
+    }
+
+    pub fn from_vec(data: Vec<Float>) -> Matrix<R, C> {
+        if R * C != data.len() {
             panic!("dimension mismatched");
         }
 
-        Matrix {
-            height: height,
-            width: width,
-            data: data,
+        let mut matrix = Matrix::new([[Float::new(0.0); C]; R]);
+        for (i, value) in data.into_iter().enumerate() {
+            matrix.data[i / C][i % C] = value;
         }
+
+        matrix
+    }
+
+    pub fn height(&self) -> usize {
+        R
+    }
+
+    pub fn width(&self) -> usize {
+        C
     }
 
     pub fn value_at(&self, row: usize, col: usize) -> Float {
-        self.data.get(row * self.height + col).unwrap().clone()
+        self.data[row][col]
+    }
+
+    pub fn transpose(&self) -> Matrix<C, R> {
+        let mut result = Matrix::new([[Float::new(0.0); R]; C]);
+        for (row, values) in self.data.iter().enumerate() {
+            for (col, value) in values.iter().enumerate() {
+                result.data[col][row] = *value;
+            }
+        }
+
+        result
+    }
+}
+
+impl<const N: usize> Matrix<N, N> {
+    pub fn identity() -> Matrix<N, N> {
+        let mut result = Matrix::new([[Float::new(0.0); N]; N]);
+        for i in 0..N {
+            result.data[i][i] = Float::new(1.0);
+        }
+
+        result
+    }
+}
+
+impl Matrix<1, 1> {
+    pub fn determinant(&self) -> Float {
+        self.data[0][0]
     }
 }
 
-impl std::ops::Mul for Matrix {
-    type Output = Matrix;
+// Removing a row and a column needs the size of the result in the type, which
+// stable const generics cannot compute from N, so each square size that
+// supports cofactor expansion is spelled out here.
+macro_rules! impl_square_matrix {
+    ($size:literal, $sub:literal) => {
+        impl Matrix<$size, $size> {
+            pub fn submatrix(&self, row: usize, col: usize) -> Matrix<$sub, $sub> {
+                if row >= $size || col >= $size {
+                    panic!("row and col must be within a range");
+                }
+
+                let mut result = Matrix::new([[Float::new(0.0); $sub]; $sub]);
+                for (i, source_row) in (0..$size).filter(|i| *i != row).enumerate() {
+                    for (j, source_col) in (0..$size).filter(|j| *j != col).enumerate() {
+                        result.data[i][j] = self.data[source_row][source_col];
+                    }
+                }
+
+                result
+            }
+
+            pub fn minor(&self, row: usize, col: usize) -> Float {
+                self.submatrix(row, col).determinant()
+            }
+
+            pub fn cofactor(&self, row: usize, col: usize) -> Float {
+                let minor = self.minor(row, col);
+                if (row + col) % 2 == 1 {
+                    return -minor;
+                }
+
+                minor
+            }
 
-    fn mul(self, other: Matrix) -> Matrix {
-        if self.width != other.height {
-            panic!("Dimension mismatched");
+            pub fn determinant(&self) -> Float {
+                (0..$size).fold(Float::new(0.0), |sum, col| {
+                    sum + self.data[0][col] * self.cofactor(0, col)
+                })
+            }
+
+            // exact: small but valid transforms such as a 0.01 scaling have
+            // determinants that equal 0 within tolerance
+            pub fn is_invertible(&self) -> bool {
+                self.determinant().value() != 0.0
+            }
+
+            pub fn inverse(&self) -> Result<Matrix<$size, $size>, String> {
+                let determinant = self.determinant();
+                if !self.is_invertible() {
+                    return Err(String::from("matrix is not invertible"));
+                }
+
+                let mut result = Matrix::new([[Float::new(0.0); $size]; $size]);
+                for row in 0..$size {
+                    for col in 0..$size {
+                        // writing to (col, row) transposes the cofactor matrix
+                        result.data[col][row] = self.cofactor(row, col) / determinant;
+                    }
+                }
+
+                Ok(result)
+            }
         }
+    };
+}
 
-        let new_height = self.height;
-        let new_width = other.width;
+impl_square_matrix!(2, 1);
+impl_square_matrix!(3, 2);
+impl_square_matrix!(4, 3);
 
-        let mut new_data: Vec<Float> = vec![];
-        for i in 0..self.height {
-            for j in 0..other.width {
+impl Matrix4 {
+    fn multiply_xyzw(&self, xyzw: [Float; 4]) -> [Float; 4] {
+        let mut result = [Float::new(0.0); 4];
+        for (value, row) in result.iter_mut().zip(self.data.iter()) {
+            for (entry, component) in row.iter().zip(xyzw.iter()) {
+                *value = *value + *entry * *component;
+            }
+        }
+
+        result
+    }
+}
+
+impl std::ops::Mul<Tuple> for Matrix4 {
+    type Output = Result<Tuple, String>;
+
+    fn mul(self, other: Tuple) -> Result<Tuple, String> {
+        let [x, y, z, w] = self.multiply_xyzw([other.x(), other.y(), other.z(), other.w()]);
+        Tuple::new(x, y, z, w)
+    }
+}
+
+impl std::ops::Mul<Tuple> for &Matrix4 {
+    type Output = Result<Tuple, String>;
+
+    fn mul(self, other: Tuple) -> Result<Tuple, String> {
+        *self * other
+    }
+}
+
+impl std::ops::Mul<Point> for Matrix4 {
+    type Output = Point;
+
+    fn mul(self, other: Point) -> Point {
+        let [x, y, z, _] = self.multiply_xyzw([other.x(), other.y(), other.z(), Float::new(1.0)]);
+        Point::new(x, y, z)
+    }
+}
+
+impl std::ops::Mul<Point> for &Matrix4 {
+    type Output = Point;
+
+    fn mul(self, other: Point) -> Point {
+        *self * other
+    }
+}
+
+impl std::ops::Mul<Vector> for Matrix4 {
+    type Output = Vector;
+
+    fn mul(self, other: Vector) -> Vector {
+        let [x, y, z, _] = self.multiply_xyzw([other.x(), other.y(), other.z(), Float::new(0.0)]);
+        Vector::new(x, y, z)
+    }
+}
+
+impl std::ops::Mul<Vector> for &Matrix4 {
+    type Output = Vector;
+
+    fn mul(self, other: Vector) -> Vector {
+        *self * other
+    }
+}
+
+impl<const R: usize, const K: usize, const C: usize> std::ops::Mul<&Matrix<K, C>>
+    for &Matrix<R, K>
+{
+    type Output = Matrix<R, C>;
+
+    fn mul(self, other: &Matrix<K, C>) -> Matrix<R, C> {
+        let mut result = Matrix::new([[Float::new(0.0); C]; R]);
+        for i in 0..R {
+            for j in 0..C {
                 let mut sum = Float::new(0.0);
-                for m in 0..self.width {
-                    println!("{} {} {}", i, j, m);
-                    sum = sum
-                        + self.data.get(i * self.width + m).unwrap().clone()
-                        * other.data.get(m * other.width + j).unwrap().clone();
+                for m in 0..K {
+                    sum = sum + self.data[i][m] * other.data[m][j];
                 }
-                new_data.push(sum);
+                result.data[i][j] = sum;
             }
         }
 
-        Matrix::from_vec(new_height, new_width, new_data)
+        result
     }
 }
 
-impl PartialEq for Matrix {
-    fn eq(&self, other: &Self) -> bool {
-        let is_dimension_match = self.width == other.width && self.height == other.height;
-        if !is_dimension_match {
+impl<const R: usize, const K: usize, const C: usize> std::ops::Mul<Matrix<K, C>> for Matrix<R, K> {
+    type Output = Matrix<R, C>;
+
+    fn mul(self, other: Matrix<K, C>) -> Matrix<R, C> {
+        &self * &other
+    }
+}
+
+impl<const R: usize, const C: usize, const R2: usize, const C2: usize> PartialEq<Matrix<R2, C2>>
+    for Matrix<R, C>
+{
+    fn eq(&self, other: &Matrix<R2, C2>) -> bool {
+        if R != R2 || C != C2 {
             return false;
         }
-        for i in 0..(self.width * self.height) {
-            if self.data.get(i) != other.data.get(i) {
-                return false;
-            }
-        }
 
-        true
+        (0..R).all(|row| (0..C).all(|col| self.value_at(row, col) == other.value_at(row, col)))
     }
 }
 
@@ -74,31 +250,35 @@ impl PartialEq for Matrix {
 mod matrix_tests {
     use super::Float;
     use super::Matrix;
+    use super::Matrix4;
+    use super::Point;
+    use super::Tuple;
+    use super::Vector;
+
+    fn matrix<const R: usize, const C: usize>(data: &[f64]) -> Matrix<R, C> {
+        Matrix::from_vec(data.iter().map(|v| Float::new(*v)).collect())
+    }
 
     #[test]
     fn can_construct_a_matrix() {
-        let matrix = Matrix::from_vec(
-            4,
-            4,
-            vec![
-                Float::new(1.0),
-                Float::new(2.0),
-                Float::new(3.0),
-                Float::new(4.0),
-                Float::new(5.5),
-                Float::new(6.5),
-                Float::new(7.5),
-                Float::new(8.5),
-                Float::new(9.0),
-                Float::new(10.0),
-                Float::new(11.0),
-                Float::new(12.0),
-                Float::new(13.5),
-                Float::new(14.5),
-                Float::new(15.5),
-                Float::new(16.5),
-            ],
-        );
+        let matrix = Matrix::<4, 4>::from_vec(vec![
+            Float::new(1.0),
+            Float::new(2.0),
+            Float::new(3.0),
+            Float::new(4.0),
+            Float::new(5.5),
+            Float::new(6.5),
+            Float::new(7.5),
+            Float::new(8.5),
+            Float::new(9.0),
+            Float::new(10.0),
+            Float::new(11.0),
+            Float::new(12.0),
+            Float::new(13.5),
+            Float::new(14.5),
+            Float::new(15.5),
+            Float::new(16.5),
+        ]);
 
         assert_eq!(matrix.value_at(0, 0), Float::new(1.0));
         assert_eq!(matrix.value_at(0, 3), Float::new(4.0));
@@ -108,143 +288,359 @@ mod matrix_tests {
         assert_eq!(matrix.value_at(3, 0), Float::new(13.5));
         assert_eq!(matrix.value_at(3, 2), Float::new(15.5));
     }
+    #[test]
+    fn can_construct_a_matrix_from_arrays() {
+        let a = Matrix::new([
+            [Float::new(-3.0), Float::new(5.0), Float::new(0.0)],
+            [Float::new(1.0), Float::new(-2.0), Float::new(-7.0)],
+        ]);
+
+        assert_eq!(a.height(), 2);
+        assert_eq!(a.width(), 3);
+        assert_eq!(a.value_at(0, 1), Float::new(5.0));
+        assert_eq!(a.value_at(1, 2), Float::new(-7.0));
+        assert_eq!(a, matrix::<2, 3>(&[-3.0, 5.0, 0.0, 1.0, -2.0, -7.0]));
+    }
+
     #[test]
     fn can_compare_equal() {
-        let matrix1 = Matrix::from_vec(
-            4,
-            4,
-            vec![
-                Float::new(1.0),
-                Float::new(2.0),
-                Float::new(3.0),
-                Float::new(4.0),
-                Float::new(5.5),
-                Float::new(6.5),
-                Float::new(7.5),
-                Float::new(8.5),
-                Float::new(9.0),
-                Float::new(10.0),
-                Float::new(11.0),
-                Float::new(12.0),
-                Float::new(13.5),
-                Float::new(14.5),
-                Float::new(15.5),
-                Float::new(16.5),
-            ],
-        );
-        let matrix2 = Matrix::from_vec(
-            4,
-            4,
-            vec![
-                Float::new(1.0),
-                Float::new(2.0),
-                Float::new(3.0),
-                Float::new(4.0),
-                Float::new(5.5),
-                Float::new(6.5),
-                Float::new(7.5),
-                Float::new(8.5),
-                Float::new(9.0),
-                Float::new(10.0),
-                Float::new(11.0),
-                Float::new(12.0),
-                Float::new(13.5),
-                Float::new(14.5),
-                Float::new(15.5),
-                Float::new(16.5),
-            ],
-        );
+        let matrix1 = Matrix::<4, 4>::from_vec(vec![
+            Float::new(1.0),
+            Float::new(2.0),
+            Float::new(3.0),
+            Float::new(4.0),
+            Float::new(5.5),
+            Float::new(6.5),
+            Float::new(7.5),
+            Float::new(8.5),
+            Float::new(9.0),
+            Float::new(10.0),
+            Float::new(11.0),
+            Float::new(12.0),
+            Float::new(13.5),
+            Float::new(14.5),
+            Float::new(15.5),
+            Float::new(16.5),
+        ]);
+        let matrix2 = Matrix::<4, 4>::from_vec(vec![
+            Float::new(1.0),
+            Float::new(2.0),
+            Float::new(3.0),
+            Float::new(4.0),
+            Float::new(5.5),
+            Float::new(6.5),
+            Float::new(7.5),
+            Float::new(8.5),
+            Float::new(9.0),
+            Float::new(10.0),
+            Float::new(11.0),
+            Float::new(12.0),
+            Float::new(13.5),
+            Float::new(14.5),
+            Float::new(15.5),
+            Float::new(16.5),
+        ]);
         assert_eq!(matrix1, matrix2);
 
-        let matrix3 = Matrix::from_vec(
-            4,
-            4,
-            vec![
-                Float::new(1.0),
-                Float::new(2.0),
-                Float::new(3.0),
-                Float::new(4.0),
-                Float::new(5.5),
-                Float::new(6.5),
-                Float::new(7.5),
-                Float::new(8.9),
-                Float::new(9.0),
-                Float::new(10.0),
-                Float::new(11.0),
-                Float::new(12.0),
-                Float::new(13.5),
-                Float::new(14.5),
-                Float::new(15.5),
-                Float::new(16.5),
-            ],
-        );
+        let matrix3 = Matrix::<4, 4>::from_vec(vec![
+            Float::new(1.0),
+            Float::new(2.0),
+            Float::new(3.0),
+            Float::new(4.0),
+            Float::new(5.5),
+            Float::new(6.5),
+            Float::new(7.5),
+            Float::new(8.9),
+            Float::new(9.0),
+            Float::new(10.0),
+            Float::new(11.0),
+            Float::new(12.0),
+            Float::new(13.5),
+            Float::new(14.5),
+            Float::new(15.5),
+            Float::new(16.5),
+        ]);
         assert_ne!(matrix1, matrix3);
 
-        let matrix4 = Matrix::from_vec(
-            2,
-            8,
-            vec![
-                Float::new(1.0),
-                Float::new(2.0),
-                Float::new(3.0),
-                Float::new(4.0),
-                Float::new(5.5),
-                Float::new(6.5),
-                Float::new(7.5),
-                Float::new(8.9),
-                Float::new(9.0),
-                Float::new(10.0),
-                Float::new(11.0),
-                Float::new(12.0),
-                Float::new(13.5),
-                Float::new(14.5),
-                Float::new(15.5),
-                Float::new(16.5),
-            ],
-        );
+        let matrix4 = Matrix::<2, 8>::from_vec(vec![
+            Float::new(1.0),
+            Float::new(2.0),
+            Float::new(3.0),
+            Float::new(4.0),
+            Float::new(5.5),
+            Float::new(6.5),
+            Float::new(7.5),
+            Float::new(8.9),
+            Float::new(9.0),
+            Float::new(10.0),
+            Float::new(11.0),
+            Float::new(12.0),
+            Float::new(13.5),
+            Float::new(14.5),
+            Float::new(15.5),
+            Float::new(16.5),
+        ]);
         assert_ne!(matrix1, matrix4);
     }
 
     #[test]
     fn can_multiply_matrices() {
-        let matrix1 = Matrix::from_vec(
-            2,
-            3,
-            vec![
-                Float::new(1.0),
-                Float::new(2.0),
-                Float::new(3.0),
-                Float::new(4.0),
-                Float::new(5.0),
-                Float::new(6.0),
-            ],
+        let matrix1 = Matrix::<2, 3>::from_vec(vec![
+            Float::new(1.0),
+            Float::new(2.0),
+            Float::new(3.0),
+            Float::new(4.0),
+            Float::new(5.0),
+            Float::new(6.0),
+        ]);
+
+        let matrix2 = Matrix::<3, 2>::from_vec(vec![
+            Float::new(7.0),
+            Float::new(8.0),
+            Float::new(9.0),
+            Float::new(10.0),
+            Float::new(11.0),
+            Float::new(12.0),
+        ]);
+
+        assert_eq!(
+            matrix1 * matrix2,
+            Matrix::<2, 2>::from_vec(vec![
+                Float::new(58.0),
+                Float::new(64.0),
+                Float::new(139.0),
+                Float::new(154.0),
+            ],)
         );
+    }
+
+    #[test]
+    fn can_multiply_by_the_identity_matrix() {
+        let a = matrix::<4, 4>(&[
+            0.0, 1.0, 2.0, 4.0, 1.0, 2.0, 4.0, 8.0, 2.0, 4.0, 8.0, 16.0, 4.0, 8.0, 16.0, 32.0,
+        ]);
+
+        assert_eq!(a * Matrix4::identity(), a);
+    }
 
-        let matrix2 = Matrix::from_vec(
-            3,
-            2,
-            vec![
-                Float::new(7.0),
-                Float::new(8.0),
-                Float::new(9.0),
-                Float::new(10.0),
-                Float::new(11.0),
-                Float::new(12.0),
-            ],
+    #[test]
+    fn can_transpose_a_matrix() {
+        let a = matrix::<4, 4>(&[
+            0.0, 9.0, 3.0, 0.0, 9.0, 8.0, 0.0, 8.0, 1.0, 8.0, 5.0, 3.0, 0.0, 0.0, 5.0, 8.0,
+        ]);
+
+        assert_eq!(
+            a.transpose(),
+            matrix::<4, 4>(&[
+                0.0, 9.0, 1.0, 0.0, 9.0, 8.0, 8.0, 0.0, 3.0, 0.0, 5.0, 5.0, 0.0, 8.0, 3.0, 8.0
+            ],)
         );
+        assert_eq!(Matrix4::identity().transpose(), Matrix4::identity());
 
+        let b = matrix::<2, 3>(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
         assert_eq!(
-            matrix1 * matrix2,
-            Matrix::from_vec(
-                2,
-                2,
-                vec![
-                    Float::new(58.0),
-                    Float::new(64.0),
-                    Float::new(139.0),
-                    Float::new(154.0),
-                ],
-            )
+            b.transpose(),
+            matrix::<3, 2>(&[1.0, 4.0, 2.0, 5.0, 3.0, 6.0])
+        );
+    }
+
+    #[test]
+    fn can_calculate_the_determinant_of_a_2x2_matrix() {
+        let a = matrix::<2, 2>(&[1.0, 5.0, -3.0, 2.0]);
+
+        assert_eq!(a.determinant(), Float::new(17.0));
+    }
+
+    #[test]
+    fn can_extract_a_submatrix() {
+        let a = matrix::<3, 3>(&[1.0, 5.0, 0.0, -3.0, 2.0, 7.0, 0.0, 6.0, -3.0]);
+        assert_eq!(a.submatrix(0, 2), matrix::<2, 2>(&[-3.0, 2.0, 0.0, 6.0]));
+
+        let b = matrix::<4, 4>(&[
+            -6.0, 1.0, 1.0, 6.0, -8.0, 5.0, 8.0, 6.0, -1.0, 0.0, 8.0, 2.0, -7.0, 1.0, -1.0, 1.0,
+        ]);
+        assert_eq!(
+            b.submatrix(2, 1),
+            matrix::<3, 3>(&[-6.0, 1.0, 6.0, -8.0, 8.0, 6.0, -7.0, -1.0, 1.0])
+        );
+    }
+
+    #[test]
+    fn can_calculate_minors_and_cofactors() {
+        let a = matrix::<3, 3>(&[3.0, 5.0, 0.0, 2.0, -1.0, -7.0, 6.0, -1.0, 5.0]);
+
+        assert_eq!(a.submatrix(1, 0).determinant(), Float::new(25.0));
+        assert_eq!(a.minor(1, 0), Float::new(25.0));
+        assert_eq!(a.minor(0, 0), Float::new(-12.0));
+        assert_eq!(a.cofactor(0, 0), Float::new(-12.0));
+        assert_eq!(a.cofactor(1, 0), Float::new(-25.0));
+    }
+
+    #[test]
+    fn can_calculate_the_determinant_of_larger_matrices() {
+        let a = matrix::<3, 3>(&[1.0, 2.0, 6.0, -5.0, 8.0, -4.0, 2.0, 6.0, 4.0]);
+        assert_eq!(a.cofactor(0, 0), Float::new(56.0));
+        assert_eq!(a.cofactor(0, 1), Float::new(12.0));
+        assert_eq!(a.cofactor(0, 2), Float::new(-46.0));
+        assert_eq!(a.determinant(), Float::new(-196.0));
+
+        let b = matrix::<4, 4>(&[
+            -2.0, -8.0, 3.0, 5.0, -3.0, 1.0, 7.0, 3.0, 1.0, 2.0, -9.0, 6.0, -6.0, 7.0, 7.0, -9.0,
+        ]);
+        assert_eq!(b.cofactor(0, 0), Float::new(690.0));
+        assert_eq!(b.cofactor(0, 1), Float::new(447.0));
+        assert_eq!(b.cofactor(0, 2), Float::new(210.0));
+        assert_eq!(b.cofactor(0, 3), Float::new(51.0));
+        assert_eq!(b.determinant(), Float::new(-4071.0));
+    }
+
+    #[test]
+    fn can_test_a_matrix_for_invertibility() {
+        let a = matrix::<4, 4>(&[
+            6.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 6.0, 4.0, -9.0, 3.0, -7.0, 9.0, 1.0, 7.0, -6.0,
+        ]);
+        assert_eq!(a.determinant(), Float::new(-2120.0));
+        assert!(a.is_invertible());
+
+        let b = matrix::<4, 4>(&[
+            -4.0, 2.0, -2.0, -3.0, 9.0, 6.0, 2.0, 6.0, 0.0, -5.0, 1.0, -5.0, 0.0, 0.0, 0.0, 0.0,
+        ]);
+        assert_eq!(b.determinant(), Float::new(0.0));
+        assert!(!b.is_invertible());
+        assert!(b.inverse().is_err());
+    }
+
+    #[test]
+    fn can_invert_a_small_uniform_scaling() {
+        let a = matrix::<4, 4>(&[
+            0.01, 0.0, 0.0, 0.0, 0.0, 0.01, 0.0, 0.0, 0.0, 0.0, 0.01, 0.0, 0.0, 0.0, 0.0, 1.0,
+        ]);
+
+        assert!(a.is_invertible());
+        assert_eq!(
+            a.inverse().unwrap(),
+            matrix::<4, 4>(&[
+                100.0, 0.0, 0.0, 0.0, 0.0, 100.0, 0.0, 0.0, 0.0, 0.0, 100.0, 0.0, 0.0, 0.0, 0.0,
+                1.0,
+            ])
+        );
+    }
+
+    #[test]
+    fn can_calculate_the_inverse_of_a_matrix() {
+        let a = matrix::<4, 4>(&[
+            -5.0, 2.0, 6.0, -8.0, 1.0, -5.0, 1.0, 8.0, 7.0, 7.0, -6.0, -7.0, 1.0, -3.0, 7.0, 4.0,
+        ]);
+        let b = a.inverse().unwrap();
+
+        assert_eq!(a.determinant(), Float::new(532.0));
+        assert_eq!(a.cofactor(2, 3), Float::new(-160.0));
+        assert_eq!(b.value_at(3, 2), Float::new(-160.0 / 532.0));
+        assert_eq!(a.cofactor(3, 2), Float::new(105.0));
+        assert_eq!(b.value_at(2, 3), Float::new(105.0 / 532.0));
+        assert_eq!(
+            b,
+            matrix::<4, 4>(&[
+                0.21805, 0.45113, 0.24060, -0.04511, -0.80827, -1.45677, -0.44361, 0.52068,
+                -0.07895, -0.22368, -0.05263, 0.19737, -0.52256, -0.81391, -0.30075, 0.30639,
+            ],)
+        );
+
+        let c = matrix::<4, 4>(&[
+            9.0, 3.0, 0.0, 9.0, -5.0, -2.0, -6.0, -3.0, -4.0, 9.0, 6.0, 4.0, -7.0, 6.0, 6.0, 2.0,
+        ]);
+        assert_eq!(
+            c.inverse().unwrap(),
+            matrix::<4, 4>(&[
+                -0.04074, -0.07778, 0.14444, -0.22222, -0.07778, 0.03333, 0.36667, -0.33333,
+                -0.02901, -0.14630, -0.10926, 0.12963, 0.17778, 0.06667, -0.26667, 0.33333,
+            ],)
+        );
+    }
+
+    #[test]
+    fn can_multiply_a_product_by_its_inverse() {
+        let a = matrix::<4, 4>(&[
+            3.0, -9.0, 7.0, 3.0, 3.0, -8.0, 2.0, -9.0, -4.0, 4.0, 4.0, 1.0, -6.0, 5.0, -1.0, 1.0,
+        ]);
+        let b = matrix::<4, 4>(&[
+            8.0, 2.0, 2.0, 2.0, 3.0, -1.0, 7.0, 0.0, 7.0, 0.0, 5.0, 4.0, 6.0, -2.0, 0.0, 5.0,
+        ]);
+        let c = a * b;
+
+        assert_eq!(c * b.inverse().unwrap(), a);
+    }
+
+    #[test]
+    fn can_multiply_a_matrix_by_a_tuple() {
+        let a = matrix::<4, 4>(&[
+            1.0, 2.0, 3.0, 4.0, 2.0, 4.0, 4.0, 2.0, 8.0, 6.0, 4.0, 1.0, 0.0, 0.0, 0.0, 1.0,
+        ]);
+        let b = Tuple::new(
+            Float::new(1.0),
+            Float::new(2.0),
+            Float::new(3.0),
+            Float::new(1.0),
+        )
+        .unwrap();
+        let expected = Tuple::new(
+            Float::new(18.0),
+            Float::new(24.0),
+            Float::new(33.0),
+            Float::new(1.0),
+        )
+        .unwrap();
+
+        assert_eq!((a * b).unwrap(), expected);
+        assert_eq!((a * b).unwrap(), expected);
+    }
+
+    #[test]
+    fn multiplying_by_a_tuple_fails_when_w_leaves_zero_or_one() {
+        let a = matrix::<4, 4>(&[
+            1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 2.0,
+        ]);
+        let b = Tuple::new(
+            Float::new(1.0),
+            Float::new(2.0),
+            Float::new(3.0),
+            Float::new(1.0),
+        )
+        .unwrap();
+
+        assert!((a * b).is_err());
+    }
+
+    #[test]
+    fn can_multiply_a_matrix_by_a_point_and_a_vector() {
+        let a = matrix::<4, 4>(&[
+            1.0, 2.0, 3.0, 4.0, 2.0, 4.0, 4.0, 2.0, 8.0, 6.0, 4.0, 1.0, 0.0, 0.0, 0.0, 1.0,
+        ]);
+        let point = Point::new(Float::new(1.0), Float::new(2.0), Float::new(3.0));
+        let vector = Vector::new(Float::new(1.0), Float::new(2.0), Float::new(3.0));
+
+        assert_eq!(
+            a * point,
+            Point::new(Float::new(18.0), Float::new(24.0), Float::new(33.0))
+        );
+        assert_eq!(
+            a * vector,
+            Vector::new(Float::new(14.0), Float::new(22.0), Float::new(32.0))
+        );
+    }
+
+    #[test]
+    fn translation_moves_points_but_not_vectors() {
+        let a = matrix::<4, 4>(&[
+            1.0, 0.0, 0.0, 5.0, 0.0, 1.0, 0.0, -3.0, 0.0, 0.0, 1.0, 2.0, 0.0, 0.0, 0.0, 1.0,
+        ]);
+        let point = Point::new(Float::new(-3.0), Float::new(4.0), Float::new(5.0));
+        let vector = Vector::new(Float::new(-3.0), Float::new(4.0), Float::new(5.0));
+
+        assert_eq!(
+            a * point,
+            Point::new(Float::new(2.0), Float::new(1.0), Float::new(7.0))
         );
+        assert_eq!(a * vector, vector);
     }
 }
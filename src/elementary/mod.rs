@@ -1,5 +1,6 @@
 pub mod float;
 pub mod matrix;
 pub mod point;
+pub mod transformation;
 pub mod tuple;
 pub mod vector;
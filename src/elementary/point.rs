@@ -4,7 +4,7 @@ use crate::elementary::vector::Vector;
 
 use std::ops;
 
-#[derive(Debug)]
+#[derive(Debug, Copy, Clone)]
 pub struct Point(Tuple);
 
 impl Point {
@@ -25,6 +25,12 @@ impl Point {
     }
 }
 
+impl From<Point> for Tuple {
+    fn from(point: Point) -> Tuple {
+        point.0
+    }
+}
+
 impl PartialEq for Point {
     fn eq(&self, other: &Self) -> bool {
         self.0 == other.0
@@ -41,6 +47,18 @@ impl ops::Sub for Point {
     }
 }
 
+impl ops::Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, other: Vector) -> Point {
+        Point::new(
+            self.x() + other.x(),
+            self.y() + other.y(),
+            self.z() + other.z(),
+        )
+    }
+}
+
 impl ops::Sub<Vector> for Point {
     type Output = Point;
 
@@ -70,6 +88,17 @@ mod point_tests {
         );
     }
 
+    #[test]
+    fn can_add_a_vector_to_a_point() {
+        let a = Point::new(Float::new(3.0), Float::new(-2.0), Float::new(5.0));
+        let b = Vector::new(Float::new(-2.0), Float::new(3.0), Float::new(1.0));
+
+        assert_eq!(
+            a + b,
+            Point::new(Float::new(1.0), Float::new(1.0), Float::new(6.0)),
+        )
+    }
+
     #[test]
     fn can_substract_vector_from_a_point() {
         let a = Point::new(Float::new(3.0), Float::new(2.0), Float::new(1.0));
@@ -0,0 +1,411 @@
+use crate::elementary::float::Float;
+use crate::elementary::matrix::Matrix4;
+use crate::elementary::point::Point;
+use crate::elementary::vector::Vector;
+
+impl Matrix4 {
+    pub fn translation(x: Float, y: Float, z: Float) -> Matrix4 {
+        let zero = Float::new(0.0);
+        let one = Float::new(1.0);
+
+        Matrix4::new([
+            [one, zero, zero, x],
+            [zero, one, zero, y],
+            [zero, zero, one, z],
+            [zero, zero, zero, one],
+        ])
+    }
+
+    pub fn scaling(x: Float, y: Float, z: Float) -> Matrix4 {
+        let zero = Float::new(0.0);
+        let one = Float::new(1.0);
+
+        Matrix4::new([
+            [x, zero, zero, zero],
+            [zero, y, zero, zero],
+            [zero, zero, z, zero],
+            [zero, zero, zero, one],
+        ])
+    }
+
+    pub fn rotation_x(radians: Float) -> Matrix4 {
+        let zero = Float::new(0.0);
+        let one = Float::new(1.0);
+        let (sin, cos) = (radians.sin(), radians.cos());
+
+        Matrix4::new([
+            [one, zero, zero, zero],
+            [zero, cos, -sin, zero],
+            [zero, sin, cos, zero],
+            [zero, zero, zero, one],
+        ])
+    }
+
+    pub fn rotation_y(radians: Float) -> Matrix4 {
+        let zero = Float::new(0.0);
+        let one = Float::new(1.0);
+        let (sin, cos) = (radians.sin(), radians.cos());
+
+        Matrix4::new([
+            [cos, zero, sin, zero],
+            [zero, one, zero, zero],
+            [-sin, zero, cos, zero],
+            [zero, zero, zero, one],
+        ])
+    }
+
+    pub fn rotation_z(radians: Float) -> Matrix4 {
+        let zero = Float::new(0.0);
+        let one = Float::new(1.0);
+        let (sin, cos) = (radians.sin(), radians.cos());
+
+        Matrix4::new([
+            [cos, -sin, zero, zero],
+            [sin, cos, zero, zero],
+            [zero, zero, one, zero],
+            [zero, zero, zero, one],
+        ])
+    }
+
+    /// Rotates around an arbitrary axis using Rodrigues' rotation formula.
+    /// The axis does not need to be normalized.
+    pub fn rotation_axis_angle(axis: Vector, radians: Float) -> Matrix4 {
+        let zero = Float::new(0.0);
+        let one = Float::new(1.0);
+        let axis = axis.normalize();
+        let (x, y, z) = (axis.x(), axis.y(), axis.z());
+        let (sin, cos) = (radians.sin(), radians.cos());
+        let t = one - cos;
+
+        Matrix4::new([
+            [
+                t * x * x + cos,
+                t * x * y - sin * z,
+                t * x * z + sin * y,
+                zero,
+            ],
+            [
+                t * x * y + sin * z,
+                t * y * y + cos,
+                t * y * z - sin * x,
+                zero,
+            ],
+            [
+                t * x * z - sin * y,
+                t * y * z + sin * x,
+                t * z * z + cos,
+                zero,
+            ],
+            [zero, zero, zero, one],
+        ])
+    }
+
+    pub fn shearing(
+        x_y: Float,
+        x_z: Float,
+        y_x: Float,
+        y_z: Float,
+        z_x: Float,
+        z_y: Float,
+    ) -> Matrix4 {
+        let zero = Float::new(0.0);
+        let one = Float::new(1.0);
+
+        Matrix4::new([
+            [one, x_y, x_z, zero],
+            [y_x, one, y_z, zero],
+            [z_x, z_y, one, zero],
+            [zero, zero, zero, one],
+        ])
+    }
+
+    // The fluent builders apply the new transformation after the existing
+    // one, so `identity().rotate_x(a).translate(..)` rotates first.
+
+    pub fn translate(self, x: Float, y: Float, z: Float) -> Matrix4 {
+        Matrix4::translation(x, y, z) * self
+    }
+
+    pub fn scale(self, x: Float, y: Float, z: Float) -> Matrix4 {
+        Matrix4::scaling(x, y, z) * self
+    }
+
+    pub fn rotate_x(self, radians: Float) -> Matrix4 {
+        Matrix4::rotation_x(radians) * self
+    }
+
+    pub fn rotate_y(self, radians: Float) -> Matrix4 {
+        Matrix4::rotation_y(radians) * self
+    }
+
+    pub fn rotate_z(self, radians: Float) -> Matrix4 {
+        Matrix4::rotation_z(radians) * self
+    }
+
+    pub fn rotate_axis_angle(self, axis: Vector, radians: Float) -> Matrix4 {
+        Matrix4::rotation_axis_angle(axis, radians) * self
+    }
+
+    pub fn shear(
+        self,
+        x_y: Float,
+        x_z: Float,
+        y_x: Float,
+        y_z: Float,
+        z_x: Float,
+        z_y: Float,
+    ) -> Matrix4 {
+        Matrix4::shearing(x_y, x_z, y_x, y_z, z_x, z_y) * self
+    }
+}
+
+/// Orients the world relative to an eye at `from` looking towards `to`, with
+/// `up` giving the approximate upward direction.
+pub fn view_transform(from: Point, to: Point, up: Vector) -> Matrix4 {
+    let zero = Float::new(0.0);
+    let one = Float::new(1.0);
+    let forward = (to - from).normalize();
+    let left = forward * up.normalize();
+    let true_up = left * forward;
+
+    let orientation = Matrix4::new([
+        [left.x(), left.y(), left.z(), zero],
+        [true_up.x(), true_up.y(), true_up.z(), zero],
+        [-forward.x(), -forward.y(), -forward.z(), zero],
+        [zero, zero, zero, one],
+    ]);
+
+    orientation * Matrix4::translation(-from.x(), -from.y(), -from.z())
+}
+
+#[cfg(test)]
+mod transformation_tests {
+    use super::view_transform;
+    use super::Float;
+    use super::Matrix4;
+    use crate::test_helpers::{point, vector};
+    use std::f64::consts::PI;
+
+    #[test]
+    fn can_translate_a_point() {
+        let transform = Matrix4::translation(Float::new(5.0), Float::new(-3.0), Float::new(2.0));
+
+        assert_eq!(transform * point(-3.0, 4.0, 5.0), point(2.0, 1.0, 7.0));
+        assert_eq!(
+            transform.inverse().unwrap() * point(-3.0, 4.0, 5.0),
+            point(-8.0, 7.0, 3.0)
+        );
+    }
+
+    #[test]
+    fn translation_does_not_affect_vectors() {
+        let transform = Matrix4::translation(Float::new(5.0), Float::new(-3.0), Float::new(2.0));
+
+        assert_eq!(transform * vector(-3.0, 4.0, 5.0), vector(-3.0, 4.0, 5.0));
+    }
+
+    #[test]
+    fn can_scale_points_and_vectors() {
+        let transform = Matrix4::scaling(Float::new(2.0), Float::new(3.0), Float::new(4.0));
+
+        assert_eq!(transform * point(-4.0, 6.0, 8.0), point(-8.0, 18.0, 32.0));
+        assert_eq!(transform * vector(-4.0, 6.0, 8.0), vector(-8.0, 18.0, 32.0));
+        assert_eq!(
+            transform.inverse().unwrap() * vector(-4.0, 6.0, 8.0),
+            vector(-2.0, 2.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn reflection_is_scaling_by_a_negative_value() {
+        let transform = Matrix4::scaling(Float::new(-1.0), Float::new(1.0), Float::new(1.0));
+
+        assert_eq!(transform * point(2.0, 3.0, 4.0), point(-2.0, 3.0, 4.0));
+    }
+
+    #[test]
+    fn can_rotate_a_point_around_the_x_axis() {
+        let half_quarter = Matrix4::rotation_x(Float::new(PI / 4.0));
+        let full_quarter = Matrix4::rotation_x(Float::new(PI / 2.0));
+
+        assert_eq!(
+            half_quarter * point(0.0, 1.0, 0.0),
+            point(0.0, 2.0_f64.sqrt() / 2.0, 2.0_f64.sqrt() / 2.0)
+        );
+        assert_eq!(full_quarter * point(0.0, 1.0, 0.0), point(0.0, 0.0, 1.0));
+        assert_eq!(
+            half_quarter.inverse().unwrap() * point(0.0, 1.0, 0.0),
+            point(0.0, 2.0_f64.sqrt() / 2.0, -(2.0_f64.sqrt()) / 2.0)
+        );
+    }
+
+    #[test]
+    fn can_rotate_a_point_around_the_y_axis() {
+        let half_quarter = Matrix4::rotation_y(Float::new(PI / 4.0));
+        let full_quarter = Matrix4::rotation_y(Float::new(PI / 2.0));
+
+        assert_eq!(
+            half_quarter * point(0.0, 0.0, 1.0),
+            point(2.0_f64.sqrt() / 2.0, 0.0, 2.0_f64.sqrt() / 2.0)
+        );
+        assert_eq!(full_quarter * point(0.0, 0.0, 1.0), point(1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn can_rotate_a_point_around_the_z_axis() {
+        let half_quarter = Matrix4::rotation_z(Float::new(PI / 4.0));
+        let full_quarter = Matrix4::rotation_z(Float::new(PI / 2.0));
+
+        assert_eq!(
+            half_quarter * point(0.0, 1.0, 0.0),
+            point(-(2.0_f64.sqrt()) / 2.0, 2.0_f64.sqrt() / 2.0, 0.0)
+        );
+        assert_eq!(full_quarter * point(0.0, 1.0, 0.0), point(-1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn axis_angle_rotation_matches_the_principal_axes() {
+        let angle = Float::new(PI / 3.0);
+
+        assert_eq!(
+            Matrix4::rotation_axis_angle(vector(1.0, 0.0, 0.0), angle),
+            Matrix4::rotation_x(angle)
+        );
+        assert_eq!(
+            Matrix4::rotation_axis_angle(vector(0.0, 2.0, 0.0), angle),
+            Matrix4::rotation_y(angle)
+        );
+        assert_eq!(
+            Matrix4::rotation_axis_angle(vector(0.0, 0.0, 1.0), angle),
+            Matrix4::rotation_z(angle)
+        );
+    }
+
+    #[test]
+    fn can_rotate_around_an_arbitrary_axis() {
+        let transform =
+            Matrix4::rotation_axis_angle(vector(1.0, 1.0, 1.0), Float::new(2.0 * PI / 3.0));
+
+        assert_eq!(transform * point(1.0, 0.0, 0.0), point(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn can_shear_a_point() {
+        let one = Float::new(1.0);
+        let zero = Float::new(0.0);
+        let p = point(2.0, 3.0, 4.0);
+
+        assert_eq!(
+            Matrix4::shearing(one, zero, zero, zero, zero, zero) * p,
+            point(5.0, 3.0, 4.0)
+        );
+        assert_eq!(
+            Matrix4::shearing(zero, one, zero, zero, zero, zero) * p,
+            point(6.0, 3.0, 4.0)
+        );
+        assert_eq!(
+            Matrix4::shearing(zero, zero, one, zero, zero, zero) * p,
+            point(2.0, 5.0, 4.0)
+        );
+        assert_eq!(
+            Matrix4::shearing(zero, zero, zero, one, zero, zero) * p,
+            point(2.0, 7.0, 4.0)
+        );
+        assert_eq!(
+            Matrix4::shearing(zero, zero, zero, zero, one, zero) * p,
+            point(2.0, 3.0, 6.0)
+        );
+        assert_eq!(
+            Matrix4::shearing(zero, zero, zero, zero, zero, one) * p,
+            point(2.0, 3.0, 7.0)
+        );
+    }
+
+    #[test]
+    fn chained_transformations_apply_in_order() {
+        let p = point(1.0, 0.0, 1.0);
+        let a = Matrix4::rotation_x(Float::new(PI / 2.0));
+        let b = Matrix4::scaling(Float::new(5.0), Float::new(5.0), Float::new(5.0));
+        let c = Matrix4::translation(Float::new(10.0), Float::new(5.0), Float::new(7.0));
+
+        let p2 = a * p;
+        assert_eq!(p2, point(1.0, -1.0, 0.0));
+        let p3 = b * p2;
+        assert_eq!(p3, point(5.0, -5.0, 0.0));
+        let p4 = c * p3;
+        assert_eq!(p4, point(15.0, 0.0, 7.0));
+
+        assert_eq!(c * b * a * p, point(15.0, 0.0, 7.0));
+    }
+
+    #[test]
+    fn fluent_transformations_apply_in_order() {
+        let transform = Matrix4::identity()
+            .rotate_x(Float::new(PI / 2.0))
+            .scale(Float::new(5.0), Float::new(5.0), Float::new(5.0))
+            .translate(Float::new(10.0), Float::new(5.0), Float::new(7.0));
+
+        assert_eq!(transform * point(1.0, 0.0, 1.0), point(15.0, 0.0, 7.0));
+    }
+
+    #[test]
+    fn view_transform_for_the_default_orientation() {
+        let transform = view_transform(
+            point(0.0, 0.0, 0.0),
+            point(0.0, 0.0, -1.0),
+            vector(0.0, 1.0, 0.0),
+        );
+
+        assert_eq!(transform, Matrix4::identity());
+    }
+
+    #[test]
+    fn view_transform_looking_in_positive_z_direction() {
+        let transform = view_transform(
+            point(0.0, 0.0, 0.0),
+            point(0.0, 0.0, 1.0),
+            vector(0.0, 1.0, 0.0),
+        );
+
+        assert_eq!(
+            transform,
+            Matrix4::scaling(Float::new(-1.0), Float::new(1.0), Float::new(-1.0))
+        );
+    }
+
+    #[test]
+    fn view_transform_moves_the_world() {
+        let transform = view_transform(
+            point(0.0, 0.0, 8.0),
+            point(0.0, 0.0, 0.0),
+            vector(0.0, 1.0, 0.0),
+        );
+
+        assert_eq!(
+            transform,
+            Matrix4::translation(Float::new(0.0), Float::new(0.0), Float::new(-8.0))
+        );
+    }
+
+    #[test]
+    fn arbitrary_view_transformation() {
+        let transform = view_transform(
+            point(1.0, 3.0, 2.0),
+            point(4.0, -2.0, 8.0),
+            vector(1.0, 1.0, 0.0),
+        );
+
+        assert_eq!(
+            transform,
+            Matrix4::from_vec(
+                [
+                    -0.50709, 0.50709, 0.67612, -2.36643, 0.76772, 0.60609, 0.12122, -2.82843,
+                    -0.35857, 0.59761, -0.71714, 0.00000, 0.00000, 0.00000, 0.00000, 1.00000,
+                ]
+                .iter()
+                .map(|v| Float::new(*v))
+                .collect()
+            )
+        );
+    }
+}
@@ -72,6 +72,7 @@ impl ops::Sub for Tuple {
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tuple_tests {
     use super::Float;
     use super::Tuple;
@@ -183,7 +184,7 @@ mod tuple_tests {
         .unwrap();
 
         assert_eq!(
-            (vector - point).unwrap(),
+            (point - vector).unwrap(),
             Tuple::new(
                 Float::new(-2.0),
                 Float::new(-4.0),
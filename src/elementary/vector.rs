@@ -1,10 +1,9 @@
 use crate::elementary::float::Float;
 use crate::elementary::tuple::Tuple;
-use std::num;
 
 use std::ops;
 
-#[derive(Debug)]
+#[derive(Debug, Copy, Clone)]
 pub struct Vector(Tuple);
 
 impl PartialEq for Vector {
@@ -13,6 +12,12 @@ impl PartialEq for Vector {
     }
 }
 
+impl From<Vector> for Tuple {
+    fn from(vector: Vector) -> Tuple {
+        vector.0
+    }
+}
+
 impl Vector {
     pub fn new(x: Float, y: Float, z: Float) -> Vector {
         Vector(Tuple::new(x, y, z, Float::new(0.0)).unwrap())
@@ -45,6 +50,10 @@ impl Vector {
     pub fn dot(&self, other: Self) -> Float {
         self.x() * other.x() + self.y() * other.y() + self.z() * other.z()
     }
+
+    pub fn reflect(&self, normal: Vector) -> Vector {
+        *self - normal * (Float::new(2.0) * self.dot(normal))
+    }
 }
 
 impl ops::Add<Vector> for Vector {
@@ -65,6 +74,14 @@ impl ops::Sub for Vector {
     }
 }
 
+impl ops::Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.x(), -self.y(), -self.z())
+    }
+}
+
 impl ops::Mul<f64> for Vector {
     type Output = Vector;
 
@@ -73,6 +90,14 @@ impl ops::Mul<f64> for Vector {
     }
 }
 
+impl ops::Mul<Float> for Vector {
+    type Output = Vector;
+
+    fn mul(self, other: Float) -> Vector {
+        Vector::new(self.x() * other, self.y() * other, self.z() * other)
+    }
+}
+
 impl ops::Mul for Vector {
     type Output = Vector;
 
@@ -121,6 +146,16 @@ mod vector_tests {
         )
     }
 
+    #[test]
+    fn can_negate_a_vector() {
+        let a = Vector::new(Float::new(1.0), Float::new(-2.0), Float::new(3.0));
+
+        assert_eq!(
+            -a,
+            Vector::new(Float::new(-1.0), Float::new(2.0), Float::new(-3.0))
+        )
+    }
+
     #[test]
     fn can_multiply_a_vector_with_a_scalar() {
         let a = Vector::new(Float::new(1.0), Float::new(-2.0), Float::new(3.0));
@@ -185,7 +220,7 @@ mod vector_tests {
             a * b,
             Vector::new(Float::new(-1.0), Float::new(2.0), Float::new(-1.0))
         );
-        
+
         let a = Vector::new(Float::new(1.0), Float::new(2.0), Float::new(3.0));
         let b = Vector::new(Float::new(2.0), Float::new(3.0), Float::new(4.0));
         assert_eq!(
@@ -193,4 +228,30 @@ mod vector_tests {
             Vector::new(Float::new(1.0), Float::new(-2.0), Float::new(1.0))
         )
     }
+
+    #[test]
+    fn can_reflect_a_vector_approaching_at_45_degrees() {
+        let v = Vector::new(Float::new(1.0), Float::new(-1.0), Float::new(0.0));
+        let n = Vector::new(Float::new(0.0), Float::new(1.0), Float::new(0.0));
+
+        assert_eq!(
+            v.reflect(n),
+            Vector::new(Float::new(1.0), Float::new(1.0), Float::new(0.0))
+        );
+    }
+
+    #[test]
+    fn can_reflect_a_vector_off_a_slanted_surface() {
+        let v = Vector::new(Float::new(0.0), Float::new(-1.0), Float::new(0.0));
+        let n = Vector::new(
+            Float::new(2.0_f64.sqrt() / 2.0),
+            Float::new(2.0_f64.sqrt() / 2.0),
+            Float::new(0.0),
+        );
+
+        assert_eq!(
+            v.reflect(n),
+            Vector::new(Float::new(1.0), Float::new(0.0), Float::new(0.0))
+        );
+    }
 }
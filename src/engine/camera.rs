@@ -0,0 +1,201 @@
+use super::canvas::Canvas;
+use super::world::World;
+use crate::elementary::float::Float;
+use crate::elementary::matrix::Matrix4;
+use crate::elementary::point::Point;
+use crate::ray::Ray;
+
+#[derive(Debug, Clone)]
+pub struct Camera {
+    hsize: usize,
+    vsize: usize,
+    field_of_view: Float,
+    transform: Matrix4,
+    inverse: Matrix4,
+    half_width: Float,
+    half_height: Float,
+    pixel_size: Float,
+}
+
+impl Camera {
+    pub fn new(hsize: usize, vsize: usize, field_of_view: Float) -> Camera {
+        let half_view = (field_of_view / Float::new(2.0)).tan();
+        let aspect = Float::new(hsize as f64) / Float::new(vsize as f64);
+
+        let (half_width, half_height) = if aspect >= Float::new(1.0) {
+            (half_view, half_view / aspect)
+        } else {
+            (half_view * aspect, half_view)
+        };
+
+        Camera {
+            hsize,
+            vsize,
+            field_of_view,
+            transform: Matrix4::identity(),
+            inverse: Matrix4::identity(),
+            half_width,
+            half_height,
+            pixel_size: half_width * Float::new(2.0) / Float::new(hsize as f64),
+        }
+    }
+
+    pub fn hsize(&self) -> usize {
+        self.hsize
+    }
+
+    pub fn vsize(&self) -> usize {
+        self.vsize
+    }
+
+    pub fn field_of_view(&self) -> Float {
+        self.field_of_view
+    }
+
+    pub fn pixel_size(&self) -> Float {
+        self.pixel_size
+    }
+
+    pub fn transform(&self) -> &Matrix4 {
+        &self.transform
+    }
+
+    pub fn set_transform(&mut self, transform: Matrix4) {
+        self.inverse = transform
+            .inverse()
+            .expect("camera transform must be invertible");
+        self.transform = transform;
+    }
+
+    pub fn ray_for_pixel(&self, x: usize, y: usize) -> Ray {
+        // offset from the edge of the canvas to the pixel's center
+        let xoffset = (Float::new(x as f64) + Float::new(0.5)) * self.pixel_size;
+        let yoffset = (Float::new(y as f64) + Float::new(0.5)) * self.pixel_size;
+
+        // the camera looks toward -z, so +x is to the left
+        let world_x = self.half_width - xoffset;
+        let world_y = self.half_height - yoffset;
+
+        let pixel = self.inverse * Point::new(world_x, world_y, Float::new(-1.0));
+        let origin = self.inverse * Point::new(Float::new(0.0), Float::new(0.0), Float::new(0.0));
+
+        Ray::new(origin, (pixel - origin).normalize())
+    }
+
+    pub fn render(&self, world: &World) -> Canvas {
+        let mut image = Canvas::new(self.hsize, self.vsize);
+        for y in 0..self.vsize {
+            for x in 0..self.hsize {
+                let ray = self.ray_for_pixel(x, y);
+                image.write_pixel(x, y, world.color_at(&ray, world.max_depth()));
+            }
+        }
+
+        image
+    }
+}
+
+#[cfg(test)]
+mod camera_tests {
+    use super::Camera;
+    use super::Float;
+    use super::Matrix4;
+    use super::World;
+    use crate::elementary::transformation::view_transform;
+    use crate::engine::light::PointLight;
+    use crate::engine::material::Material;
+    use crate::shape::sphere::Sphere;
+    use crate::shape::Shape;
+    use crate::test_helpers::{color, point, vector};
+    use std::f64::consts::PI;
+
+    #[test]
+    fn can_construct_a_camera() {
+        let camera = Camera::new(160, 120, Float::new(PI / 2.0));
+
+        assert_eq!(camera.hsize(), 160);
+        assert_eq!(camera.vsize(), 120);
+        assert_eq!(camera.field_of_view(), Float::new(PI / 2.0));
+        assert_eq!(*camera.transform(), Matrix4::identity());
+    }
+
+    #[test]
+    fn pixel_size_for_a_horizontal_canvas() {
+        let camera = Camera::new(200, 125, Float::new(PI / 2.0));
+
+        assert_eq!(camera.pixel_size(), Float::new(0.01));
+    }
+
+    #[test]
+    fn pixel_size_for_a_vertical_canvas() {
+        let camera = Camera::new(125, 200, Float::new(PI / 2.0));
+
+        assert_eq!(camera.pixel_size(), Float::new(0.01));
+    }
+
+    #[test]
+    fn ray_through_the_center_of_the_canvas() {
+        let camera = Camera::new(201, 101, Float::new(PI / 2.0));
+        let ray = camera.ray_for_pixel(100, 50);
+
+        assert_eq!(ray.origin(), point(0.0, 0.0, 0.0));
+        assert_eq!(ray.direction(), vector(0.0, 0.0, -1.0));
+    }
+
+    #[test]
+    fn ray_through_a_corner_of_the_canvas() {
+        let camera = Camera::new(201, 101, Float::new(PI / 2.0));
+        let ray = camera.ray_for_pixel(0, 0);
+
+        assert_eq!(ray.origin(), point(0.0, 0.0, 0.0));
+        assert_eq!(ray.direction(), vector(0.66519, 0.33259, -0.66851));
+    }
+
+    #[test]
+    fn ray_when_the_camera_is_transformed() {
+        let mut camera = Camera::new(201, 101, Float::new(PI / 2.0));
+        camera.set_transform(
+            Matrix4::identity()
+                .translate(Float::new(0.0), Float::new(-2.0), Float::new(5.0))
+                .rotate_y(Float::new(PI / 4.0)),
+        );
+        let ray = camera.ray_for_pixel(100, 50);
+        let value = 2.0_f64.sqrt() / 2.0;
+
+        assert_eq!(ray.origin(), point(0.0, 2.0, -5.0));
+        assert_eq!(ray.direction(), vector(value, 0.0, -value));
+    }
+
+    #[test]
+    fn can_render_a_world() {
+        let mut world = World::new();
+        world.add_light(PointLight::new(
+            point(-10.0, 10.0, -10.0),
+            color(1.0, 1.0, 1.0),
+        ));
+        let mut outer = Sphere::new();
+        let mut material = Material::default();
+        material.set_color(color(0.8, 1.0, 0.6));
+        material.set_diffuse(Float::new(0.7));
+        material.set_specular(Float::new(0.2));
+        outer.set_material(material);
+        world.add_object(outer);
+        let mut inner = Sphere::new();
+        inner.set_transform(Matrix4::scaling(
+            Float::new(0.5),
+            Float::new(0.5),
+            Float::new(0.5),
+        ));
+        world.add_object(inner);
+
+        let mut camera = Camera::new(11, 11, Float::new(PI / 2.0));
+        camera.set_transform(view_transform(
+            point(0.0, 0.0, -5.0),
+            point(0.0, 0.0, 0.0),
+            vector(0.0, 1.0, 0.0),
+        ));
+        let image = camera.render(&world);
+
+        assert_eq!(*image.pixel_at(5, 5), color(0.38066, 0.47583, 0.2855));
+    }
+}
@@ -1,6 +1,7 @@
 use super::color::Color;
 use crate::elementary::float::Float;
 
+#[derive(Debug, Clone, PartialEq)]
 pub struct Canvas {
     data: Vec<Vec<Color>>,
     width: usize,
@@ -10,8 +11,8 @@ pub struct Canvas {
 impl Canvas {
     pub fn new(width: usize, height: usize) -> Canvas {
         Canvas {
-            width: width,
-            height: height,
+            width,
+            height,
             data: vec![
                 vec![Color::new(Float::new(0.0), Float::new(0.0), Float::new(0.0)); width];
                 height
@@ -19,6 +20,56 @@ impl Canvas {
         }
     }
 
+    /// Reads a plain (`P3`) PPM image, as written by `save`, scaling each
+    /// channel by the image's maximum value.
+    pub fn from_ppm(input: &str) -> Result<Canvas, String> {
+        // comments run from a `#` to the end of the line
+        let mut tokens = input
+            .lines()
+            .flat_map(|line| line.split('#').next().unwrap_or("").split_whitespace());
+
+        if tokens.next() != Some("P3") {
+            return Err(String::from("only plain P3 PPM images are supported"));
+        }
+
+        let mut next_number = |what: &str| -> Result<usize, String> {
+            let token = tokens.next().ok_or_else(|| format!("missing {}", what))?;
+            token
+                .parse::<usize>()
+                .map_err(|_| format!("invalid {} {:?}", what, token))
+        };
+        let width = next_number("width")?;
+        let height = next_number("height")?;
+        if width == 0 || height == 0 {
+            return Err(format!("an image of {}x{} has no pixels", width, height));
+        }
+        let max = next_number("maximum value")?;
+        if !(1..=65535).contains(&max) {
+            return Err(format!("maximum value {} is outside 1 to 65535", max));
+        }
+
+        let mut next_sample = || -> Result<Float, String> {
+            let sample = next_number("pixel value")?;
+            if sample > max {
+                return Err(format!(
+                    "pixel value {} is above the maximum {}",
+                    sample, max
+                ));
+            }
+
+            Ok(Float::new(sample as f64) / Float::new(max as f64))
+        };
+        let mut canvas = Canvas::new(width, height);
+        for y in 0..height {
+            for x in 0..width {
+                let (r, g, b) = (next_sample()?, next_sample()?, next_sample()?);
+                canvas.write_pixel(x, y, Color::new(r, g, b));
+            }
+        }
+
+        Ok(canvas)
+    }
+
     pub fn width(&self) -> usize {
         self.width
     }
@@ -38,24 +89,19 @@ impl Canvas {
     }
 
     pub fn write_pixel(&mut self, x: usize, y: usize, c: Color) -> Color {
-        std::mem::replace(&mut self.data[y][x], c.clone())
+        std::mem::replace(&mut self.data[y][x], c)
     }
 
     pub fn save(&self) -> String {
         // write the ppm headers
-        let mut data = format!(
-            "{}\n{} {}\n255\n",
-            "P3",
-            self.width().to_string(),
-            self.height().to_string()
-        );
+        let mut data = format!("{}\n{} {}\n255\n", "P3", self.width(), self.height());
         for y in 0..self.height() {
             let mut line = String::from("");
             for x in 0..self.width() {
-                let color_str = (*self.pixel_at(x, y)).clone().to_255();
+                let color_str = self.pixel_at(x, y).to_255();
 
                 if x == 0 {
-                    line = format!("{}", color_str);
+                    line = color_str;
                 } else {
                     line = format!("{} {}", line, color_str);
                 }
@@ -124,4 +170,83 @@ mod canvas_tests {
             String::from("P3\n5 3\n255\n255 0 0 0 0 0 0 0 0 0 0 0 0 0 0\n0 0 0 0 0 0 0 127 0 0 0 0 0 0 0\n0 0 0 0 0 0 0 0 0 0 0 0 0 0 255\n")
         );
     }
+
+    #[test]
+    fn reading_a_ppm_with_the_wrong_magic_number() {
+        assert!(Canvas::from_ppm("P32\n1 1\n255\n0 0 0").is_err());
+    }
+
+    #[test]
+    fn reading_a_ppm_returns_a_canvas_of_the_right_size() {
+        let canvas = Canvas::from_ppm(&format!("P3\n10 2\n255\n{}", "0 0 0 ".repeat(20))).unwrap();
+
+        assert_eq!(canvas.width(), 10);
+        assert_eq!(canvas.height(), 2);
+    }
+
+    #[test]
+    fn reading_pixel_data_from_a_ppm() {
+        let input = "P3\n4 3\n255\n\
+                     255 127 0  0 127 255  127 255 0  255 255 255\n\
+                     0 0 0  255 0 0  0 255 0  0 0 255\n\
+                     255 255 0  0 255 255  255 0 255  127 127 127";
+        let canvas = Canvas::from_ppm(input).unwrap();
+        let color =
+            |r: f64, g: f64, b: f64| Color::new(Float::new(r), Float::new(g), Float::new(b));
+        let cases = [
+            (0, 0, color(1.0, 0.49804, 0.0)),
+            (1, 0, color(0.0, 0.49804, 1.0)),
+            (2, 0, color(0.49804, 1.0, 0.0)),
+            (3, 0, color(1.0, 1.0, 1.0)),
+            (0, 1, color(0.0, 0.0, 0.0)),
+            (1, 1, color(1.0, 0.0, 0.0)),
+            (2, 1, color(0.0, 1.0, 0.0)),
+            (3, 1, color(0.0, 0.0, 1.0)),
+            (0, 2, color(1.0, 1.0, 0.0)),
+            (1, 2, color(0.0, 1.0, 1.0)),
+            (2, 2, color(1.0, 0.0, 1.0)),
+            (3, 2, color(0.49804, 0.49804, 0.49804)),
+        ];
+
+        for (x, y, expected) in cases {
+            assert_eq!(*canvas.pixel_at(x, y), expected);
+        }
+    }
+
+    #[test]
+    fn reading_a_ppm_ignores_comments_and_scales_by_the_maximum() {
+        let input = "P3\n# a comment\n1 1\n100 # another\n100 50 0";
+        let canvas = Canvas::from_ppm(input).unwrap();
+
+        assert_eq!(
+            *canvas.pixel_at(0, 0),
+            Color::new(Float::new(1.0), Float::new(0.5), Float::new(0.0))
+        );
+    }
+
+    #[test]
+    fn reading_a_ppm_without_pixels_fails() {
+        assert!(Canvas::from_ppm("P3\n0 0\n255\n").is_err());
+        assert!(Canvas::from_ppm("P3\n2 0\n255\n").is_err());
+        assert!(Canvas::from_ppm("P3\n0 2\n255\n").is_err());
+    }
+
+    #[test]
+    fn reading_a_ppm_with_an_unusable_maximum_fails() {
+        assert!(Canvas::from_ppm("P3\n1 1\n0\n0 0 0").is_err());
+        assert!(Canvas::from_ppm("P3\n1 1\n65536\n0 0 0").is_err());
+        assert!(Canvas::from_ppm("P3\n1 1\n65535\n0 0 65535").is_ok());
+    }
+
+    #[test]
+    fn reading_a_ppm_with_a_sample_above_the_maximum_fails() {
+        assert!(Canvas::from_ppm("P3\n1 1\n100\n0 101 0").is_err());
+        assert!(Canvas::from_ppm("P3\n1 1\n100\n0 100 0").is_ok());
+    }
+
+    #[test]
+    fn reading_a_ppm_with_missing_pixels_fails() {
+        assert!(Canvas::from_ppm("P3\n2 1\n255\n0 0 0").is_err());
+        assert!(Canvas::from_ppm("P3\n1 1\n255\n0 zero 0").is_err());
+    }
 }
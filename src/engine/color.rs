@@ -4,6 +4,7 @@ use std::ops;
 #[derive(Debug, Clone, Copy)]
 pub struct Color(Float, Float, Float);
 
+#[allow(dead_code)]
 #[derive(Debug, Clone, Copy)]
 pub struct Color255(Float, Float, Float);
 
@@ -51,9 +52,9 @@ impl Color {
 
         format!(
             "{} {} {}",
-            (r_clamped.value() as i64).to_string(),
-            (g_clamped.value() as i64).to_string(),
-            (b_clamped.value() as i64).to_string()
+            r_clamped.value() as i64,
+            g_clamped.value() as i64,
+            b_clamped.value() as i64
         )
     }
 }
@@ -0,0 +1,236 @@
+use super::color::Color;
+use super::material::Material;
+use crate::elementary::float::Float;
+use crate::elementary::point::Point;
+use crate::elementary::vector::Vector;
+use crate::shape::Shape;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PointLight {
+    position: Point,
+    intensity: Color,
+}
+
+impl PointLight {
+    pub fn new(position: Point, intensity: Color) -> PointLight {
+        PointLight {
+            position,
+            intensity,
+        }
+    }
+
+    pub fn position(&self) -> Point {
+        self.position
+    }
+
+    pub fn intensity(&self) -> Color {
+        self.intensity
+    }
+}
+
+/// Shades a point with the Phong reflection model, summing the ambient,
+/// diffuse and specular contributions of a single light. A point in shadow
+/// only receives the ambient term. The object is needed to place the
+/// material's pattern, if it has one.
+pub fn lighting(
+    material: &Material,
+    object: &dyn Shape,
+    light: &PointLight,
+    point: Point,
+    eyev: Vector,
+    normalv: Vector,
+    in_shadow: bool,
+) -> Color {
+    let black = Color::new(Float::new(0.0), Float::new(0.0), Float::new(0.0));
+
+    let effective_color = material.color_at(object, point) * light.intensity();
+    let lightv = (light.position() - point).normalize();
+    let ambient = effective_color * material.ambient();
+    if in_shadow {
+        return ambient;
+    }
+
+    // a negative cosine means the light is on the other side of the surface
+    let light_dot_normal = lightv.dot(normalv);
+    if light_dot_normal < Float::new(0.0) {
+        return ambient;
+    }
+
+    let diffuse = effective_color * material.diffuse() * light_dot_normal;
+
+    let reflectv = (-lightv).reflect(normalv);
+    let reflect_dot_eye = reflectv.dot(eyev);
+    let specular = if reflect_dot_eye <= Float::new(0.0) {
+        black
+    } else {
+        let factor = reflect_dot_eye.powf(material.shininess());
+        light.intensity() * material.specular() * factor
+    };
+
+    ambient + diffuse + specular
+}
+
+#[cfg(test)]
+mod light_tests {
+    use super::lighting;
+    use super::Float;
+    use super::Material;
+    use super::PointLight;
+    use crate::engine::pattern::stripe::Stripe;
+    use crate::shape::sphere::Sphere;
+    use crate::test_helpers::{color, point, vector};
+
+    #[test]
+    fn point_light_has_a_position_and_intensity() {
+        let light = PointLight::new(point(0.0, 0.0, 0.0), color(1.0, 1.0, 1.0));
+
+        assert_eq!(light.position(), point(0.0, 0.0, 0.0));
+        assert_eq!(light.intensity(), color(1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn lighting_with_the_eye_between_the_light_and_the_surface() {
+        let material = Material::default();
+        let light = PointLight::new(point(0.0, 0.0, -10.0), color(1.0, 1.0, 1.0));
+
+        assert_eq!(
+            lighting(
+                &material,
+                &Sphere::new(),
+                &light,
+                point(0.0, 0.0, 0.0),
+                vector(0.0, 0.0, -1.0),
+                vector(0.0, 0.0, -1.0),
+                false
+            ),
+            color(1.9, 1.9, 1.9)
+        );
+    }
+
+    #[test]
+    fn lighting_with_the_eye_offset_45_degrees() {
+        let material = Material::default();
+        let light = PointLight::new(point(0.0, 0.0, -10.0), color(1.0, 1.0, 1.0));
+        let value = 2.0_f64.sqrt() / 2.0;
+
+        assert_eq!(
+            lighting(
+                &material,
+                &Sphere::new(),
+                &light,
+                point(0.0, 0.0, 0.0),
+                vector(0.0, value, -value),
+                vector(0.0, 0.0, -1.0),
+                false
+            ),
+            color(1.0, 1.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn lighting_with_the_light_offset_45_degrees() {
+        let material = Material::default();
+        let light = PointLight::new(point(0.0, 10.0, -10.0), color(1.0, 1.0, 1.0));
+
+        assert_eq!(
+            lighting(
+                &material,
+                &Sphere::new(),
+                &light,
+                point(0.0, 0.0, 0.0),
+                vector(0.0, 0.0, -1.0),
+                vector(0.0, 0.0, -1.0),
+                false
+            ),
+            color(0.7364, 0.7364, 0.7364)
+        );
+    }
+
+    #[test]
+    fn lighting_with_the_eye_in_the_path_of_the_reflection() {
+        let material = Material::default();
+        let light = PointLight::new(point(0.0, 10.0, -10.0), color(1.0, 1.0, 1.0));
+        let value = 2.0_f64.sqrt() / 2.0;
+
+        assert_eq!(
+            lighting(
+                &material,
+                &Sphere::new(),
+                &light,
+                point(0.0, 0.0, 0.0),
+                vector(0.0, -value, -value),
+                vector(0.0, 0.0, -1.0),
+                false
+            ),
+            color(1.6364, 1.6364, 1.6364)
+        );
+    }
+
+    #[test]
+    fn lighting_with_the_light_behind_the_surface() {
+        let material = Material::default();
+        let light = PointLight::new(point(0.0, 0.0, 10.0), color(1.0, 1.0, 1.0));
+
+        assert_eq!(
+            lighting(
+                &material,
+                &Sphere::new(),
+                &light,
+                point(0.0, 0.0, 0.0),
+                vector(0.0, 0.0, -1.0),
+                vector(0.0, 0.0, -1.0),
+                false
+            ),
+            color(0.1, 0.1, 0.1)
+        );
+    }
+
+    #[test]
+    fn lighting_with_the_surface_in_shadow() {
+        let material = Material::default();
+        let light = PointLight::new(point(0.0, 0.0, -10.0), color(1.0, 1.0, 1.0));
+
+        assert_eq!(
+            lighting(
+                &material,
+                &Sphere::new(),
+                &light,
+                point(0.0, 0.0, 0.0),
+                vector(0.0, 0.0, -1.0),
+                vector(0.0, 0.0, -1.0),
+                true
+            ),
+            color(0.1, 0.1, 0.1)
+        );
+    }
+
+    #[test]
+    fn lighting_with_a_pattern_applied() {
+        let white = color(1.0, 1.0, 1.0);
+        let black = color(0.0, 0.0, 0.0);
+        let mut material = Material::default();
+        material.set_pattern(Stripe::new(white, black));
+        material.set_ambient(Float::new(1.0));
+        material.set_diffuse(Float::new(0.0));
+        material.set_specular(Float::new(0.0));
+        let sphere = Sphere::new();
+        let light = PointLight::new(point(0.0, 0.0, -10.0), white);
+        let eyev = vector(0.0, 0.0, -1.0);
+        let normalv = vector(0.0, 0.0, -1.0);
+
+        for (x, expected) in [(0.9, white), (1.1, black)] {
+            assert_eq!(
+                lighting(
+                    &material,
+                    &sphere,
+                    &light,
+                    point(x, 0.0, 0.0),
+                    eyev,
+                    normalv,
+                    false
+                ),
+                expected
+            );
+        }
+    }
+}
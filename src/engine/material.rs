@@ -0,0 +1,206 @@
+use super::color::Color;
+use super::pattern::Pattern;
+use crate::elementary::float::Float;
+use crate::elementary::point::Point;
+use crate::shape::Shape;
+
+use std::rc::Rc;
+
+/// The surface properties of a shape. The surface is colored either by a
+/// flat color or by a pattern; setting one replaces the other. Patterns are
+/// shared between clones of a material.
+#[derive(Debug, Clone)]
+pub struct Material {
+    color: Color,
+    pattern: Option<Rc<dyn Pattern>>,
+    ambient: Float,
+    diffuse: Float,
+    specular: Float,
+    shininess: Float,
+    reflective: Float,
+    transparency: Float,
+    refractive_index: Float,
+}
+
+impl Material {
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    pub fn set_color(&mut self, color: Color) {
+        self.color = color;
+        self.pattern = None;
+    }
+
+    pub fn pattern(&self) -> Option<&dyn Pattern> {
+        self.pattern.as_deref()
+    }
+
+    pub fn set_pattern<P: Pattern + 'static>(&mut self, pattern: P) {
+        self.pattern = Some(Rc::new(pattern));
+    }
+
+    /// The color of the surface of `object` at `world_point`.
+    pub fn color_at(&self, object: &dyn Shape, world_point: Point) -> Color {
+        match &self.pattern {
+            Some(pattern) => pattern.pattern_at_shape(object, world_point),
+            None => self.color,
+        }
+    }
+
+    pub fn ambient(&self) -> Float {
+        self.ambient
+    }
+
+    pub fn set_ambient(&mut self, ambient: Float) {
+        self.ambient = ambient;
+    }
+
+    pub fn diffuse(&self) -> Float {
+        self.diffuse
+    }
+
+    pub fn set_diffuse(&mut self, diffuse: Float) {
+        self.diffuse = diffuse;
+    }
+
+    pub fn specular(&self) -> Float {
+        self.specular
+    }
+
+    pub fn set_specular(&mut self, specular: Float) {
+        self.specular = specular;
+    }
+
+    pub fn shininess(&self) -> Float {
+        self.shininess
+    }
+
+    pub fn set_shininess(&mut self, shininess: Float) {
+        self.shininess = shininess;
+    }
+
+    /// How much of the surrounding scene the surface mirrors, from 0 for
+    /// none to 1 for a perfect mirror.
+    pub fn reflective(&self) -> Float {
+        self.reflective
+    }
+
+    pub fn set_reflective(&mut self, reflective: Float) {
+        self.reflective = reflective;
+    }
+
+    /// How much light passes through the surface, from 0 for opaque to 1
+    /// for fully transparent.
+    pub fn transparency(&self) -> Float {
+        self.transparency
+    }
+
+    pub fn set_transparency(&mut self, transparency: Float) {
+        self.transparency = transparency;
+    }
+
+    /// How strongly light bends on entering the material: 1 for a vacuum,
+    /// around 1.333 for water and 1.5 for glass.
+    pub fn refractive_index(&self) -> Float {
+        self.refractive_index
+    }
+
+    pub fn set_refractive_index(&mut self, refractive_index: Float) {
+        self.refractive_index = refractive_index;
+    }
+}
+
+impl Default for Material {
+    fn default() -> Self {
+        Material {
+            color: Color::new(Float::new(1.0), Float::new(1.0), Float::new(1.0)),
+            pattern: None,
+            ambient: Float::new(0.1),
+            diffuse: Float::new(0.9),
+            specular: Float::new(0.9),
+            shininess: Float::new(200.0),
+            reflective: Float::new(0.0),
+            transparency: Float::new(0.0),
+            refractive_index: Float::new(1.0),
+        }
+    }
+}
+
+impl PartialEq for Material {
+    fn eq(&self, other: &Self) -> bool {
+        let same_pattern = match (&self.pattern, &other.pattern) {
+            (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        };
+
+        same_pattern
+            && self.color == other.color
+            && self.ambient == other.ambient
+            && self.diffuse == other.diffuse
+            && self.specular == other.specular
+            && self.shininess == other.shininess
+            && self.reflective == other.reflective
+            && self.transparency == other.transparency
+            && self.refractive_index == other.refractive_index
+    }
+}
+
+#[cfg(test)]
+mod material_tests {
+    use super::Color;
+    use super::Float;
+    use super::Material;
+    use super::Point;
+    use crate::engine::pattern::stripe::Stripe;
+    use crate::shape::sphere::Sphere;
+
+    #[test]
+    fn can_create_the_default_material() {
+        let material = Material::default();
+
+        assert_eq!(
+            material.color(),
+            Color::new(Float::new(1.0), Float::new(1.0), Float::new(1.0))
+        );
+        assert_eq!(material.ambient(), Float::new(0.1));
+        assert_eq!(material.diffuse(), Float::new(0.9));
+        assert_eq!(material.specular(), Float::new(0.9));
+        assert_eq!(material.shininess(), Float::new(200.0));
+        assert_eq!(material.reflective(), Float::new(0.0));
+        assert_eq!(material.transparency(), Float::new(0.0));
+        assert_eq!(material.refractive_index(), Float::new(1.0));
+    }
+
+    #[test]
+    fn a_material_colors_with_its_pattern_when_it_has_one() {
+        let white = Color::new(Float::new(1.0), Float::new(1.0), Float::new(1.0));
+        let black = Color::new(Float::new(0.0), Float::new(0.0), Float::new(0.0));
+        let sphere = Sphere::new();
+        let mut material = Material::default();
+        material.set_pattern(Stripe::new(white, black));
+        let at = |x: f64| Point::new(Float::new(x), Float::new(0.0), Float::new(0.0));
+
+        assert!(material.pattern().is_some());
+        assert_eq!(material.color_at(&sphere, at(0.9)), white);
+        assert_eq!(material.color_at(&sphere, at(1.1)), black);
+
+        material.set_color(black);
+
+        assert!(material.pattern().is_none());
+        assert_eq!(material.color_at(&sphere, at(0.9)), black);
+    }
+
+    #[test]
+    fn clones_share_a_pattern_and_compare_equal() {
+        let white = Color::new(Float::new(1.0), Float::new(1.0), Float::new(1.0));
+        let mut material = Material::default();
+        material.set_pattern(Stripe::new(white, white));
+        let mut other = Material::default();
+        other.set_pattern(Stripe::new(white, white));
+
+        assert_eq!(material.clone(), material);
+        assert_ne!(other, material);
+    }
+}
@@ -1,2 +1,7 @@
+pub mod camera;
 pub mod canvas;
 pub mod color;
+pub mod light;
+pub mod material;
+pub mod pattern;
+pub mod world;
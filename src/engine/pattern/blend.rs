@@ -0,0 +1,109 @@
+use super::{Pattern, PatternData, PatternPair};
+use crate::elementary::float::Float;
+use crate::elementary::point::Point;
+use crate::engine::color::Color;
+
+/// Mixes two patterns at every point. The weight is how much of the second
+/// pattern shows through, an even 50/50 by default.
+#[derive(Debug)]
+pub struct Blend {
+    data: PatternData,
+    patterns: PatternPair,
+    weight: Float,
+}
+
+impl Blend {
+    pub fn new(patterns: PatternPair) -> Blend {
+        Blend {
+            data: PatternData::new(),
+            patterns,
+            weight: Float::new(0.5),
+        }
+    }
+
+    pub fn patterns(&self) -> &PatternPair {
+        &self.patterns
+    }
+
+    pub fn weight(&self) -> Float {
+        self.weight
+    }
+
+    pub fn set_weight(&mut self, weight: Float) {
+        self.weight = weight;
+    }
+}
+
+impl Pattern for Blend {
+    fn data(&self) -> &PatternData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut PatternData {
+        &mut self.data
+    }
+
+    fn pattern_at(&self, point: Point) -> Color {
+        self.patterns.mix(self.weight, point)
+    }
+}
+
+#[cfg(test)]
+mod blend_tests {
+    use super::Blend;
+    use super::Float;
+    use super::Pattern;
+    use super::PatternPair;
+    use crate::elementary::matrix::Matrix4;
+    use crate::engine::pattern::stripe::Stripe;
+    use crate::test_helpers::{color, point};
+    use std::f64::consts::PI;
+
+    #[test]
+    fn blending_two_solid_patterns_averages_them() {
+        let pattern = Blend::new(PatternPair::solid(
+            color(1.0, 0.0, 0.0),
+            color(0.0, 0.0, 1.0),
+        ));
+
+        assert_eq!(pattern.weight(), Float::new(0.5));
+        assert_eq!(
+            pattern.pattern_at(point(0.0, 0.0, 0.0)),
+            color(0.5, 0.0, 0.5)
+        );
+    }
+
+    #[test]
+    fn the_weight_favors_the_second_pattern() {
+        let mut pattern = Blend::new(PatternPair::solid(
+            color(1.0, 1.0, 1.0),
+            color(0.0, 0.0, 0.0),
+        ));
+        pattern.set_weight(Float::new(0.25));
+
+        assert_eq!(
+            pattern.pattern_at(point(0.0, 0.0, 0.0)),
+            color(0.75, 0.75, 0.75)
+        );
+    }
+
+    #[test]
+    fn blending_crossed_stripes_makes_a_plaid() {
+        let white = color(1.0, 1.0, 1.0);
+        let black = color(0.0, 0.0, 0.0);
+        let along_x = Stripe::new(white, black);
+        let mut along_z = Stripe::new(white, black);
+        along_z.set_transform(Matrix4::rotation_y(Float::new(PI / 2.0)));
+        let pattern = Blend::new(PatternPair::new(along_x, along_z));
+        let cases = [
+            (point(0.5, 0.0, -0.5), color(1.0, 1.0, 1.0)),
+            (point(1.5, 0.0, -0.5), color(0.5, 0.5, 0.5)),
+            (point(0.5, 0.0, 0.5), color(0.5, 0.5, 0.5)),
+            (point(1.5, 0.0, 0.5), color(0.0, 0.0, 0.0)),
+        ];
+
+        for (p, expected) in cases {
+            assert_eq!(pattern.pattern_at(p), expected);
+        }
+    }
+}
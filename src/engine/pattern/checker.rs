@@ -0,0 +1,102 @@
+use super::{is_even, Pattern, PatternData, PatternPair};
+use crate::elementary::point::Point;
+use crate::engine::color::Color;
+
+/// Alternating unit cubes of two colors, like a 3D chessboard.
+#[derive(Debug)]
+pub struct Checker {
+    data: PatternData,
+    patterns: PatternPair,
+}
+
+impl Checker {
+    pub fn new(a: Color, b: Color) -> Checker {
+        Checker::with_patterns(PatternPair::solid(a, b))
+    }
+
+    pub fn with_patterns(patterns: PatternPair) -> Checker {
+        Checker {
+            data: PatternData::new(),
+            patterns,
+        }
+    }
+
+    pub fn patterns(&self) -> &PatternPair {
+        &self.patterns
+    }
+}
+
+impl Pattern for Checker {
+    fn data(&self) -> &PatternData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut PatternData {
+        &mut self.data
+    }
+
+    fn pattern_at(&self, point: Point) -> Color {
+        let sum = point.x().floor() + point.y().floor() + point.z().floor();
+
+        self.patterns.pick(is_even(sum), point)
+    }
+}
+
+#[cfg(test)]
+mod checker_tests {
+    use super::Checker;
+    use super::Pattern;
+    use super::PatternPair;
+    use crate::elementary::float::Float;
+    use crate::elementary::matrix::Matrix4;
+    use crate::engine::pattern::solid::Solid;
+    use crate::engine::pattern::stripe::Stripe;
+    use crate::test_helpers::{color, point};
+
+    #[test]
+    fn checkers_should_repeat_in_each_dimension() {
+        let white = color(1.0, 1.0, 1.0);
+        let black = color(0.0, 0.0, 0.0);
+        let pattern = Checker::new(white, black);
+        let cases = [
+            (point(0.0, 0.0, 0.0), white),
+            (point(0.99, 0.0, 0.0), white),
+            (point(1.01, 0.0, 0.0), black),
+            (point(0.0, 0.99, 0.0), white),
+            (point(0.0, 1.01, 0.0), black),
+            (point(0.0, 0.0, 0.99), white),
+            (point(0.0, 0.0, 1.01), black),
+            (point(-0.5, 0.0, 0.0), black),
+            (point(-0.5, -0.5, 0.0), white),
+        ];
+
+        for (p, expected) in cases {
+            assert_eq!(pattern.pattern_at(p), expected);
+        }
+    }
+
+    #[test]
+    fn checker_squares_can_hold_other_patterns() {
+        let white = color(1.0, 1.0, 1.0);
+        let black = color(0.0, 0.0, 0.0);
+        let red = color(1.0, 0.0, 0.0);
+        let mut fine_stripes = Stripe::new(white, black);
+        fine_stripes.set_transform(Matrix4::scaling(
+            Float::new(0.25),
+            Float::new(1.0),
+            Float::new(1.0),
+        ));
+        let pattern = Checker::with_patterns(PatternPair::new(fine_stripes, Solid::new(red)));
+        let cases = [
+            (point(0.1, 0.0, 0.0), white),
+            (point(0.3, 0.0, 0.0), black),
+            (point(0.6, 0.0, 0.0), white),
+            (point(1.1, 0.0, 0.0), red),
+            (point(1.3, 1.0, 0.0), black),
+        ];
+
+        for (p, expected) in cases {
+            assert_eq!(pattern.pattern_at(p), expected);
+        }
+    }
+}
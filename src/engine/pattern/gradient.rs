@@ -0,0 +1,67 @@
+use super::{Pattern, PatternData, PatternPair};
+use crate::elementary::point::Point;
+use crate::engine::color::Color;
+
+/// Blends linearly from one color to the other across each unit of x,
+/// starting over at every whole number.
+#[derive(Debug)]
+pub struct Gradient {
+    data: PatternData,
+    patterns: PatternPair,
+}
+
+impl Gradient {
+    pub fn new(a: Color, b: Color) -> Gradient {
+        Gradient::with_patterns(PatternPair::solid(a, b))
+    }
+
+    pub fn with_patterns(patterns: PatternPair) -> Gradient {
+        Gradient {
+            data: PatternData::new(),
+            patterns,
+        }
+    }
+
+    pub fn patterns(&self) -> &PatternPair {
+        &self.patterns
+    }
+}
+
+impl Pattern for Gradient {
+    fn data(&self) -> &PatternData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut PatternData {
+        &mut self.data
+    }
+
+    fn pattern_at(&self, point: Point) -> Color {
+        let fraction = point.x() - point.x().floor();
+
+        self.patterns.mix(fraction, point)
+    }
+}
+
+#[cfg(test)]
+mod gradient_tests {
+    use super::Gradient;
+    use super::Pattern;
+    use crate::test_helpers::{color, point};
+
+    #[test]
+    fn a_gradient_linearly_interpolates_between_colors() {
+        let pattern = Gradient::new(color(1.0, 1.0, 1.0), color(0.0, 0.0, 0.0));
+        let cases = [
+            (0.0, color(1.0, 1.0, 1.0)),
+            (0.25, color(0.75, 0.75, 0.75)),
+            (0.5, color(0.5, 0.5, 0.5)),
+            (0.75, color(0.25, 0.25, 0.25)),
+            (1.25, color(0.75, 0.75, 0.75)),
+        ];
+
+        for (x, expected) in cases {
+            assert_eq!(pattern.pattern_at(point(x, 0.0, 0.0)), expected);
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use super::{Pattern, PatternData, PatternPair};
+use crate::elementary::float::Float;
+use crate::elementary::point::Point;
+use crate::engine::color::Color;
+use crate::noise::{turbulence, Simplex};
+
+/// A fine speckle of simplex turbulence, shading from the first pattern to
+/// the second. The strength scales the turbulence before it is clamped, so
+/// higher values give more of the second pattern.
+#[derive(Debug)]
+pub struct Granite {
+    data: PatternData,
+    patterns: PatternPair,
+    noise: Simplex,
+    octaves: usize,
+    strength: Float,
+}
+
+impl Granite {
+    pub fn new(a: Color, b: Color) -> Granite {
+        Granite::with_patterns(PatternPair::solid(a, b))
+    }
+
+    pub fn with_patterns(patterns: PatternPair) -> Granite {
+        Granite {
+            data: PatternData::new(),
+            patterns,
+            noise: Simplex::new(),
+            octaves: 8,
+            strength: Float::new(1.5),
+        }
+    }
+
+    pub fn patterns(&self) -> &PatternPair {
+        &self.patterns
+    }
+
+    pub fn set_seed(&mut self, seed: u64) {
+        self.noise = Simplex::with_seed(seed);
+    }
+
+    pub fn octaves(&self) -> usize {
+        self.octaves
+    }
+
+    pub fn set_octaves(&mut self, octaves: usize) {
+        self.octaves = octaves;
+    }
+
+    pub fn strength(&self) -> Float {
+        self.strength
+    }
+
+    pub fn set_strength(&mut self, strength: Float) {
+        self.strength = strength;
+    }
+}
+
+impl Pattern for Granite {
+    fn data(&self) -> &PatternData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut PatternData {
+        &mut self.data
+    }
+
+    fn pattern_at(&self, point: Point) -> Color {
+        let fraction =
+            (turbulence(&self.noise, point, self.octaves) * self.strength).min(Float::new(1.0));
+
+        self.patterns.mix(fraction, point)
+    }
+}
+
+#[cfg(test)]
+mod granite_tests {
+    use super::Float;
+    use super::Granite;
+    use super::Pattern;
+    use super::Point;
+    use crate::test_helpers::{color, point};
+
+    fn granite() -> Granite {
+        Granite::new(color(0.0, 0.0, 0.0), color(1.0, 1.0, 1.0))
+    }
+
+    fn samples() -> impl Iterator<Item = Point> {
+        (0..200).map(|i| point(i as f64 * 0.137, i as f64 * 0.291, i as f64 * 0.053))
+    }
+
+    #[test]
+    fn without_strength_granite_is_the_first_pattern() {
+        let mut pattern = granite();
+        pattern.set_strength(Float::new(0.0));
+
+        assert!(samples().all(|p| pattern.pattern_at(p) == color(0.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn granite_speckles_between_its_colors() {
+        let pattern = granite();
+        let shades = samples()
+            .map(|p| pattern.pattern_at(p).r().value())
+            .collect::<Vec<f64>>();
+
+        assert!(shades.iter().all(|shade| (0.0..=1.0).contains(shade)));
+        assert!(shades.iter().any(|shade| *shade < 0.2));
+        assert!(shades.iter().any(|shade| *shade > 0.4));
+    }
+
+    #[test]
+    fn granite_follows_its_seed() {
+        let pattern = granite();
+        let mut reseeded = granite();
+        reseeded.set_seed(11);
+
+        assert!(samples().any(|p| pattern.pattern_at(p) != reseeded.pattern_at(p)));
+    }
+}
@@ -0,0 +1,237 @@
+use crate::elementary::float::Float;
+use crate::elementary::point::Point;
+
+use std::f64::consts::PI;
+
+/// The ways a point on a shape's surface is turned into `u` and `v`, each
+/// from 0 to 1. Points are taken in pattern space, so the mappings assume
+/// the unit-sized primitives centered at the origin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UvMapping {
+    /// Longitude and latitude on a sphere.
+    Spherical,
+    /// The xz plane, repeating every unit.
+    Planar,
+    /// Around the y axis, repeating every unit of height.
+    Cylindrical,
+    /// Each face of a cube gets the whole of `u` and `v`.
+    Cubic,
+}
+
+impl UvMapping {
+    pub fn map(&self, point: Point) -> (Float, Float) {
+        match self {
+            UvMapping::Spherical => spherical_map(point),
+            UvMapping::Planar => planar_map(point),
+            UvMapping::Cylindrical => cylindrical_map(point),
+            UvMapping::Cubic => {
+                let (_, u, v) = cube_map(point);
+                (u, v)
+            }
+        }
+    }
+}
+
+/// One face of the cube from -1 to 1 on every axis, named as seen from the
+/// front, which faces +z.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CubeFace {
+    Left,
+    Front,
+    Right,
+    Back,
+    Up,
+    Down,
+}
+
+impl CubeFace {
+    /// The face a point lies on, chosen by its largest coordinate.
+    pub fn from_point(point: Point) -> CubeFace {
+        let (x, y, z) = (point.x().value(), point.y().value(), point.z().value());
+        let coord = x.abs().max(y.abs()).max(z.abs());
+
+        if coord == x {
+            CubeFace::Right
+        } else if coord == -x {
+            CubeFace::Left
+        } else if coord == y {
+            CubeFace::Up
+        } else if coord == -y {
+            CubeFace::Down
+        } else if coord == z {
+            CubeFace::Front
+        } else {
+            CubeFace::Back
+        }
+    }
+}
+
+pub fn spherical_map(point: Point) -> (Float, Float) {
+    let (x, y, z) = (point.x().value(), point.y().value(), point.z().value());
+    let theta = x.atan2(z);
+    let radius = (x * x + y * y + z * z).sqrt();
+    let phi = (y / radius).acos();
+
+    // theta runs from -pi to pi, flipped so u grows counterclockwise when
+    // seen from above
+    let raw_u = theta / (2.0 * PI);
+    let u = 1.0 - (raw_u + 0.5);
+    let v = 1.0 - phi / PI;
+
+    (Float::new(u), Float::new(v))
+}
+
+pub fn planar_map(point: Point) -> (Float, Float) {
+    (
+        Float::new(point.x().value().rem_euclid(1.0)),
+        Float::new(point.z().value().rem_euclid(1.0)),
+    )
+}
+
+pub fn cylindrical_map(point: Point) -> (Float, Float) {
+    let theta = point.x().value().atan2(point.z().value());
+    let raw_u = theta / (2.0 * PI);
+    let u = 1.0 - (raw_u + 0.5);
+
+    (Float::new(u), Float::new(point.y().value().rem_euclid(1.0)))
+}
+
+/// The face a point lies on together with its `u` and `v` on that face,
+/// each face laid out as if unfolded around the front.
+pub fn cube_map(point: Point) -> (CubeFace, Float, Float) {
+    let (x, y, z) = (point.x().value(), point.y().value(), point.z().value());
+    let face = CubeFace::from_point(point);
+    let (u, v) = match face {
+        CubeFace::Front => (x + 1.0, y + 1.0),
+        CubeFace::Back => (1.0 - x, y + 1.0),
+        CubeFace::Left => (z + 1.0, y + 1.0),
+        CubeFace::Right => (1.0 - z, y + 1.0),
+        CubeFace::Up => (x + 1.0, 1.0 - z),
+        CubeFace::Down => (x + 1.0, z + 1.0),
+    };
+
+    (
+        face,
+        Float::new(u.rem_euclid(2.0) / 2.0),
+        Float::new(v.rem_euclid(2.0) / 2.0),
+    )
+}
+
+#[cfg(test)]
+mod mapping_tests {
+    use super::cube_map;
+    use super::cylindrical_map;
+    use super::planar_map;
+    use super::spherical_map;
+    use super::CubeFace;
+    use super::Float;
+    use super::UvMapping;
+    use crate::test_helpers::point;
+    use std::f64::consts::FRAC_1_SQRT_2;
+
+    fn uv(u: f64, v: f64) -> (Float, Float) {
+        (Float::new(u), Float::new(v))
+    }
+
+    #[test]
+    fn using_a_spherical_mapping_on_a_3d_point() {
+        let cases = vec![
+            (point(0.0, 0.0, -1.0), uv(0.0, 0.5)),
+            (point(1.0, 0.0, 0.0), uv(0.25, 0.5)),
+            (point(0.0, 0.0, 1.0), uv(0.5, 0.5)),
+            (point(-1.0, 0.0, 0.0), uv(0.75, 0.5)),
+            (point(0.0, 1.0, 0.0), uv(0.5, 1.0)),
+            (point(0.0, -1.0, 0.0), uv(0.5, 0.0)),
+            (point(FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0.0), uv(0.25, 0.75)),
+        ];
+
+        for (p, expected) in cases {
+            assert_eq!(spherical_map(p), expected);
+        }
+    }
+
+    #[test]
+    fn using_a_planar_mapping_on_a_3d_point() {
+        let cases = vec![
+            (point(0.25, 0.0, 0.5), uv(0.25, 0.5)),
+            (point(0.25, 0.0, -0.25), uv(0.25, 0.75)),
+            (point(0.25, 0.5, -0.25), uv(0.25, 0.75)),
+            (point(1.25, 0.0, 0.5), uv(0.25, 0.5)),
+            (point(0.25, 0.0, -1.75), uv(0.25, 0.25)),
+            (point(1.0, 0.0, -1.0), uv(0.0, 0.0)),
+            (point(0.0, 0.0, 0.0), uv(0.0, 0.0)),
+        ];
+
+        for (p, expected) in cases {
+            assert_eq!(planar_map(p), expected);
+        }
+    }
+
+    #[test]
+    fn using_a_cylindrical_mapping_on_a_3d_point() {
+        let cases = vec![
+            (point(0.0, 0.0, -1.0), uv(0.0, 0.0)),
+            (point(0.0, 0.5, -1.0), uv(0.0, 0.5)),
+            (point(0.0, 1.0, -1.0), uv(0.0, 0.0)),
+            (point(FRAC_1_SQRT_2, 0.5, -FRAC_1_SQRT_2), uv(0.125, 0.5)),
+            (point(1.0, 0.5, 0.0), uv(0.25, 0.5)),
+            (point(FRAC_1_SQRT_2, 0.5, FRAC_1_SQRT_2), uv(0.375, 0.5)),
+            (point(0.0, -0.25, 1.0), uv(0.5, 0.75)),
+            (point(-FRAC_1_SQRT_2, 0.5, FRAC_1_SQRT_2), uv(0.625, 0.5)),
+            (point(-1.0, 1.25, 0.0), uv(0.75, 0.25)),
+            (point(-FRAC_1_SQRT_2, 0.5, -FRAC_1_SQRT_2), uv(0.875, 0.5)),
+        ];
+
+        for (p, expected) in cases {
+            assert_eq!(cylindrical_map(p), expected);
+        }
+    }
+
+    #[test]
+    fn identifying_the_face_of_a_cube_from_a_point() {
+        let cases = vec![
+            (point(-1.0, 0.5, -0.25), CubeFace::Left),
+            (point(1.1, -0.75, 0.8), CubeFace::Right),
+            (point(0.1, 0.6, 0.9), CubeFace::Front),
+            (point(-0.7, 0.0, -2.0), CubeFace::Back),
+            (point(0.5, 1.0, 0.9), CubeFace::Up),
+            (point(-0.2, -1.3, 1.1), CubeFace::Down),
+        ];
+
+        for (p, expected) in cases {
+            assert_eq!(CubeFace::from_point(p), expected);
+        }
+    }
+
+    #[test]
+    fn uv_mapping_each_face_of_a_cube() {
+        let cases = vec![
+            (point(-0.5, 0.5, 1.0), CubeFace::Front, uv(0.25, 0.75)),
+            (point(0.5, -0.5, 1.0), CubeFace::Front, uv(0.75, 0.25)),
+            (point(0.5, 0.5, -1.0), CubeFace::Back, uv(0.25, 0.75)),
+            (point(-0.5, -0.5, -1.0), CubeFace::Back, uv(0.75, 0.25)),
+            (point(-1.0, 0.5, -0.5), CubeFace::Left, uv(0.25, 0.75)),
+            (point(-1.0, -0.5, 0.5), CubeFace::Left, uv(0.75, 0.25)),
+            (point(1.0, 0.5, 0.5), CubeFace::Right, uv(0.25, 0.75)),
+            (point(1.0, -0.5, -0.5), CubeFace::Right, uv(0.75, 0.25)),
+            (point(-0.5, 1.0, -0.5), CubeFace::Up, uv(0.25, 0.75)),
+            (point(0.5, 1.0, 0.5), CubeFace::Up, uv(0.75, 0.25)),
+            (point(-0.5, -1.0, 0.5), CubeFace::Down, uv(0.25, 0.75)),
+            (point(0.5, -1.0, -0.5), CubeFace::Down, uv(0.75, 0.25)),
+        ];
+
+        for (p, face, (u, v)) in cases {
+            assert_eq!(cube_map(p), (face, u, v));
+        }
+    }
+
+    #[test]
+    fn a_mapping_dispatches_to_its_function() {
+        let p = point(0.3, 0.4, -0.6);
+
+        assert_eq!(UvMapping::Spherical.map(p), spherical_map(p));
+        assert_eq!(UvMapping::Planar.map(p), planar_map(p));
+        assert_eq!(UvMapping::Cylindrical.map(p), cylindrical_map(p));
+        assert_eq!(UvMapping::Cubic.map(point(0.5, -0.5, 1.0)), uv(0.75, 0.25));
+    }
+}
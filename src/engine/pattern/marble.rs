@@ -0,0 +1,131 @@
+use super::{Pattern, PatternData, PatternPair};
+use crate::elementary::float::Float;
+use crate::elementary::point::Point;
+use crate::engine::color::Color;
+use crate::noise::{turbulence, Perlin};
+
+use std::f64::consts::PI;
+
+/// Veins running across x, one per unit, bent by Perlin turbulence. The
+/// strength is how far in x a vein can be pushed.
+#[derive(Debug)]
+pub struct Marble {
+    data: PatternData,
+    patterns: PatternPair,
+    noise: Perlin,
+    octaves: usize,
+    strength: Float,
+}
+
+impl Marble {
+    pub fn new(a: Color, b: Color) -> Marble {
+        Marble::with_patterns(PatternPair::solid(a, b))
+    }
+
+    pub fn with_patterns(patterns: PatternPair) -> Marble {
+        Marble {
+            data: PatternData::new(),
+            patterns,
+            noise: Perlin::new(),
+            octaves: 6,
+            strength: Float::new(2.0),
+        }
+    }
+
+    pub fn patterns(&self) -> &PatternPair {
+        &self.patterns
+    }
+
+    pub fn set_seed(&mut self, seed: u64) {
+        self.noise = Perlin::with_seed(seed);
+    }
+
+    pub fn octaves(&self) -> usize {
+        self.octaves
+    }
+
+    pub fn set_octaves(&mut self, octaves: usize) {
+        self.octaves = octaves;
+    }
+
+    pub fn strength(&self) -> Float {
+        self.strength
+    }
+
+    pub fn set_strength(&mut self, strength: Float) {
+        self.strength = strength;
+    }
+}
+
+impl Pattern for Marble {
+    fn data(&self) -> &PatternData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut PatternData {
+        &mut self.data
+    }
+
+    fn pattern_at(&self, point: Point) -> Color {
+        let shift = turbulence(&self.noise, point, self.octaves) * self.strength;
+        let fraction = (((point.x() + shift).value() * PI).sin() + 1.0) / 2.0;
+
+        self.patterns.mix(Float::new(fraction), point)
+    }
+}
+
+#[cfg(test)]
+mod marble_tests {
+    use super::Float;
+    use super::Marble;
+    use super::Pattern;
+    use super::Point;
+    use crate::test_helpers::{color, point};
+
+    fn marble() -> Marble {
+        Marble::new(color(1.0, 1.0, 1.0), color(0.0, 0.0, 0.0))
+    }
+
+    fn samples() -> impl Iterator<Item = Point> {
+        (0..200).map(|i| point(i as f64 * 0.137, i as f64 * 0.291, i as f64 * 0.053))
+    }
+
+    #[test]
+    fn without_turbulence_marble_is_a_sine_wave_in_x() {
+        let mut pattern = marble();
+        pattern.set_strength(Float::new(0.0));
+
+        let cases = vec![
+            (point(0.0, 0.0, 0.0), color(0.5, 0.5, 0.5)),
+            (point(0.5, 3.0, -2.0), color(0.0, 0.0, 0.0)),
+            (point(1.0, 0.0, 0.0), color(0.5, 0.5, 0.5)),
+            (point(1.5, 0.0, 7.0), color(1.0, 1.0, 1.0)),
+        ];
+
+        for (p, expected) in cases {
+            assert_eq!(pattern.pattern_at(p), expected);
+        }
+    }
+
+    #[test]
+    fn turbulence_bends_the_veins() {
+        let mut flat = marble();
+        flat.set_strength(Float::new(0.0));
+        let pattern = marble();
+
+        assert!(samples().any(|p| pattern.pattern_at(p) != flat.pattern_at(p)));
+    }
+
+    #[test]
+    fn marble_stays_between_its_colors_and_follows_the_seed() {
+        let pattern = marble();
+        let mut reseeded = marble();
+        reseeded.set_seed(9);
+
+        for p in samples() {
+            let shade = pattern.pattern_at(p).r().value();
+            assert!((0.0..=1.0).contains(&shade));
+        }
+        assert!(samples().any(|p| pattern.pattern_at(p) != reseeded.pattern_at(p)));
+    }
+}
@@ -0,0 +1,271 @@
+pub mod blend;
+pub mod checker;
+pub mod gradient;
+pub mod granite;
+pub mod mapping;
+pub mod marble;
+pub mod perturbed;
+pub mod ring;
+pub mod solid;
+pub mod stripe;
+pub mod texture_map;
+pub mod uv;
+pub mod wood;
+
+use super::color::Color;
+use crate::elementary::float::Float;
+use crate::elementary::matrix::Matrix4;
+use crate::elementary::point::Point;
+use crate::shape::Shape;
+use solid::Solid;
+
+use std::fmt;
+
+/// The transform every pattern carries, with its inverse cached as for
+/// shapes.
+#[derive(Debug, Clone)]
+pub struct PatternData {
+    transform: Matrix4,
+    inverse: Matrix4,
+}
+
+impl PatternData {
+    pub fn new() -> PatternData {
+        PatternData {
+            transform: Matrix4::identity(),
+            inverse: Matrix4::identity(),
+        }
+    }
+}
+
+impl Default for PatternData {
+    fn default() -> Self {
+        PatternData::new()
+    }
+}
+
+/// The two patterns a two-tone pattern picks or blends between. Each is
+/// looked up in the owning pattern's space, then through its own transform,
+/// so plain colors and whole nested patterns work alike.
+#[derive(Debug)]
+pub struct PatternPair {
+    a: Box<dyn Pattern>,
+    b: Box<dyn Pattern>,
+}
+
+impl PatternPair {
+    pub fn new<A, B>(a: A, b: B) -> PatternPair
+    where
+        A: Pattern + 'static,
+        B: Pattern + 'static,
+    {
+        PatternPair {
+            a: Box::new(a),
+            b: Box::new(b),
+        }
+    }
+
+    pub fn solid(a: Color, b: Color) -> PatternPair {
+        PatternPair::new(Solid::new(a), Solid::new(b))
+    }
+
+    pub fn a(&self) -> &dyn Pattern {
+        self.a.as_ref()
+    }
+
+    pub fn b(&self) -> &dyn Pattern {
+        self.b.as_ref()
+    }
+
+    /// The first pattern's color at `point` if `first` holds, otherwise the
+    /// second's.
+    pub fn pick(&self, first: bool, point: Point) -> Color {
+        if first {
+            self.a.transformed_pattern_at(point)
+        } else {
+            self.b.transformed_pattern_at(point)
+        }
+    }
+
+    /// Interpolates between the two colors at `point`, from all of the first
+    /// at 0 to all of the second at 1.
+    pub fn mix(&self, fraction: Float, point: Point) -> Color {
+        let a = self.a.transformed_pattern_at(point);
+        let b = self.b.transformed_pattern_at(point);
+
+        a + (b - a) * fraction
+    }
+}
+
+/// A pattern colors space rather than a surface: implementors map a point
+/// in pattern space to a color, and `pattern_at_shape` takes a world point
+/// through the object's transforms and then the pattern's own.
+pub trait Pattern: fmt::Debug {
+    fn data(&self) -> &PatternData;
+
+    fn data_mut(&mut self) -> &mut PatternData;
+
+    fn pattern_at(&self, point: Point) -> Color;
+
+    fn transform(&self) -> &Matrix4 {
+        &self.data().transform
+    }
+
+    fn inverse(&self) -> &Matrix4 {
+        &self.data().inverse
+    }
+
+    fn set_transform(&mut self, transform: Matrix4) {
+        let data = self.data_mut();
+        data.inverse = transform
+            .inverse()
+            .expect("pattern transform must be invertible");
+        data.transform = transform;
+    }
+
+    /// The color at a point given in the space this pattern is placed in,
+    /// which is object space or the space of an enclosing pattern.
+    fn transformed_pattern_at(&self, point: Point) -> Color {
+        self.pattern_at(*self.inverse() * point)
+    }
+
+    fn pattern_at_shape(&self, object: &dyn Shape, world_point: Point) -> Color {
+        self.transformed_pattern_at(object.world_to_object(world_point))
+    }
+}
+
+/// Whether `value` falls in an even-numbered unit interval, the test the
+/// alternating patterns are built on.
+fn is_even(value: Float) -> bool {
+    value.floor().value().rem_euclid(2.0) == 0.0
+}
+
+#[cfg(test)]
+mod pattern_tests {
+    use super::Color;
+    use super::Float;
+    use super::Matrix4;
+    use super::Pattern;
+    use super::PatternData;
+    use super::PatternPair;
+    use super::Point;
+    use crate::shape::group::Group;
+    use crate::shape::sphere::Sphere;
+    use crate::shape::Shape;
+    use crate::test_helpers::{color, point};
+
+    #[derive(Debug, Default)]
+    struct TestPattern {
+        data: PatternData,
+    }
+
+    impl Pattern for TestPattern {
+        fn data(&self) -> &PatternData {
+            &self.data
+        }
+
+        fn data_mut(&mut self) -> &mut PatternData {
+            &mut self.data
+        }
+
+        fn pattern_at(&self, point: Point) -> Color {
+            Color::new(point.x(), point.y(), point.z())
+        }
+    }
+
+    fn scaling(factor: f64) -> Matrix4 {
+        Matrix4::scaling(Float::new(factor), Float::new(factor), Float::new(factor))
+    }
+
+    #[test]
+    fn the_default_pattern_transformation() {
+        let pattern = TestPattern::default();
+
+        assert_eq!(*pattern.transform(), Matrix4::identity());
+    }
+
+    #[test]
+    fn assigning_a_transformation() {
+        let mut pattern = TestPattern::default();
+        let transform = Matrix4::translation(Float::new(1.0), Float::new(2.0), Float::new(3.0));
+        pattern.set_transform(transform);
+
+        assert_eq!(*pattern.transform(), transform);
+    }
+
+    #[test]
+    fn a_pattern_with_an_object_transformation() {
+        let mut sphere = Sphere::new();
+        sphere.set_transform(scaling(2.0));
+        let pattern = TestPattern::default();
+
+        assert_eq!(
+            pattern.pattern_at_shape(&sphere, point(2.0, 3.0, 4.0)),
+            color(1.0, 1.5, 2.0)
+        );
+    }
+
+    #[test]
+    fn a_pattern_with_a_pattern_transformation() {
+        let sphere = Sphere::new();
+        let mut pattern = TestPattern::default();
+        pattern.set_transform(scaling(2.0));
+
+        assert_eq!(
+            pattern.pattern_at_shape(&sphere, point(2.0, 3.0, 4.0)),
+            color(1.0, 1.5, 2.0)
+        );
+    }
+
+    #[test]
+    fn a_pattern_with_both_an_object_and_a_pattern_transformation() {
+        let mut sphere = Sphere::new();
+        sphere.set_transform(scaling(2.0));
+        let mut pattern = TestPattern::default();
+        pattern.set_transform(Matrix4::translation(
+            Float::new(0.5),
+            Float::new(1.0),
+            Float::new(1.5),
+        ));
+
+        assert_eq!(
+            pattern.pattern_at_shape(&sphere, point(2.5, 3.0, 3.5)),
+            color(0.75, 0.5, 0.25)
+        );
+    }
+
+    #[test]
+    fn a_pattern_on_an_object_inside_a_group() {
+        let mut group = Group::new();
+        group.set_transform(scaling(2.0));
+        let mut sphere = Sphere::new();
+        sphere.set_transform(Matrix4::translation(
+            Float::new(5.0),
+            Float::new(0.0),
+            Float::new(0.0),
+        ));
+        group.add_child(sphere);
+        let pattern = TestPattern::default();
+
+        assert_eq!(
+            pattern.pattern_at_shape(group.children()[0].as_ref(), point(10.0, 2.0, 4.0)),
+            color(0.0, 1.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn a_pattern_pair_picks_or_mixes_its_patterns() {
+        let mut shifted = TestPattern::default();
+        shifted.set_transform(Matrix4::translation(
+            Float::new(1.0),
+            Float::new(0.0),
+            Float::new(0.0),
+        ));
+        let pair = PatternPair::new(TestPattern::default(), shifted);
+        let p = point(2.0, 1.0, 0.0);
+
+        assert_eq!(pair.pick(true, p), color(2.0, 1.0, 0.0));
+        assert_eq!(pair.pick(false, p), color(1.0, 1.0, 0.0));
+        assert_eq!(pair.mix(Float::new(0.25), p), color(1.75, 1.0, 0.0));
+    }
+}
@@ -0,0 +1,155 @@
+use super::{Pattern, PatternData};
+use crate::elementary::float::Float;
+use crate::elementary::point::Point;
+use crate::engine::color::Color;
+use crate::noise::{Noise, Perlin};
+
+/// Jitters the point another pattern is looked up at by Perlin noise, so
+/// straight edges wobble. The scale is the largest distance a point can be
+/// moved along each axis.
+#[derive(Debug)]
+pub struct Perturbed {
+    data: PatternData,
+    pattern: Box<dyn Pattern>,
+    scale: Float,
+    noise: Perlin,
+}
+
+impl Perturbed {
+    pub fn new<P: Pattern + 'static>(pattern: P) -> Perturbed {
+        Perturbed {
+            data: PatternData::new(),
+            pattern: Box::new(pattern),
+            scale: Float::new(0.2),
+            noise: Perlin::new(),
+        }
+    }
+
+    pub fn pattern(&self) -> &dyn Pattern {
+        self.pattern.as_ref()
+    }
+
+    pub fn scale(&self) -> Float {
+        self.scale
+    }
+
+    pub fn set_scale(&mut self, scale: Float) {
+        self.scale = scale;
+    }
+
+    pub fn set_seed(&mut self, seed: u64) {
+        self.noise = Perlin::with_seed(seed);
+    }
+}
+
+impl Pattern for Perturbed {
+    fn data(&self) -> &PatternData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut PatternData {
+        &mut self.data
+    }
+
+    fn pattern_at(&self, point: Point) -> Color {
+        // sample the noise at shifted points so each axis moves on its own
+        let offset = |shift: f64| {
+            let shifted = Point::new(point.x(), point.y(), point.z() + Float::new(shift));
+            self.noise.noise(shifted) * self.scale
+        };
+        let jittered = Point::new(
+            point.x() + offset(0.0),
+            point.y() + offset(17.3),
+            point.z() + offset(41.9),
+        );
+
+        self.pattern.transformed_pattern_at(jittered)
+    }
+}
+
+#[cfg(test)]
+mod perturbed_tests {
+    use super::Color;
+    use super::Float;
+    use super::Pattern;
+    use super::Perturbed;
+    use super::Point;
+    use crate::engine::pattern::stripe::Stripe;
+    use crate::test_helpers::point;
+
+    fn stripes() -> Stripe {
+        Stripe::new(
+            Color::new(Float::new(1.0), Float::new(1.0), Float::new(1.0)),
+            Color::new(Float::new(0.0), Float::new(0.0), Float::new(0.0)),
+        )
+    }
+
+    #[test]
+    fn without_a_scale_the_pattern_is_unchanged() {
+        let plain = stripes();
+        let mut pattern = Perturbed::new(stripes());
+        pattern.set_scale(Float::new(0.0));
+
+        for i in 0..100 {
+            let p = point(i as f64 * 0.137, i as f64 * 0.291, i as f64 * 0.053);
+            assert_eq!(pattern.pattern_at(p), plain.pattern_at(p));
+        }
+    }
+
+    #[test]
+    fn perturbing_moves_the_stripe_edges() {
+        let plain = stripes();
+        let pattern = Perturbed::new(stripes());
+        let points = (0..200)
+            .map(|i| {
+                point(
+                    0.95 + (i % 10) as f64 * 0.01,
+                    i as f64 * 0.37,
+                    i as f64 * 0.11,
+                )
+            })
+            .collect::<Vec<Point>>();
+        let changed = points
+            .iter()
+            .filter(|p| pattern.pattern_at(**p) != plain.pattern_at(**p))
+            .count();
+
+        // near an edge some lookups cross it, but far from one none do
+        assert!(changed > 0);
+        assert_eq!(
+            pattern.pattern_at(point(0.5, 0.0, 0.0)),
+            plain.pattern_at(point(0.5, 0.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn perturbing_is_deterministic() {
+        let first = Perturbed::new(stripes());
+        let second = Perturbed::new(stripes());
+
+        for i in 0..100 {
+            let p = point(i as f64 * 0.173, i as f64 * 0.071, i as f64 * 0.029);
+            assert_eq!(first.pattern_at(p), second.pattern_at(p));
+        }
+    }
+
+    #[test]
+    fn perturbing_follows_the_seed() {
+        let pattern = Perturbed::new(stripes());
+        let mut reseeded = Perturbed::new(stripes());
+        reseeded.set_seed(5);
+        let points = (0..200)
+            .map(|i| {
+                point(
+                    0.9 + (i % 20) as f64 * 0.01,
+                    i as f64 * 0.37,
+                    i as f64 * 0.11,
+                )
+            })
+            .collect::<Vec<Point>>();
+
+        assert!(points
+            .iter()
+            .any(|p| pattern.pattern_at(*p) != reseeded.pattern_at(*p)));
+    }
+}
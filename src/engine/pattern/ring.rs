@@ -0,0 +1,69 @@
+use super::{is_even, Pattern, PatternData, PatternPair};
+use crate::elementary::point::Point;
+use crate::engine::color::Color;
+
+/// Concentric unit-wide rings around the y axis, alternating between two
+/// colors.
+#[derive(Debug)]
+pub struct Ring {
+    data: PatternData,
+    patterns: PatternPair,
+}
+
+impl Ring {
+    pub fn new(a: Color, b: Color) -> Ring {
+        Ring::with_patterns(PatternPair::solid(a, b))
+    }
+
+    pub fn with_patterns(patterns: PatternPair) -> Ring {
+        Ring {
+            data: PatternData::new(),
+            patterns,
+        }
+    }
+
+    pub fn patterns(&self) -> &PatternPair {
+        &self.patterns
+    }
+}
+
+impl Pattern for Ring {
+    fn data(&self) -> &PatternData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut PatternData {
+        &mut self.data
+    }
+
+    fn pattern_at(&self, point: Point) -> Color {
+        let distance = (point.x() * point.x() + point.z() * point.z()).sqrt();
+
+        self.patterns.pick(is_even(distance), point)
+    }
+}
+
+#[cfg(test)]
+mod ring_tests {
+    use super::Pattern;
+    use super::Ring;
+    use crate::test_helpers::{color, point};
+
+    #[test]
+    fn a_ring_should_extend_in_both_x_and_z() {
+        let white = color(1.0, 1.0, 1.0);
+        let black = color(0.0, 0.0, 0.0);
+        let pattern = Ring::new(white, black);
+        let cases = [
+            (point(0.0, 0.0, 0.0), white),
+            (point(1.0, 0.0, 0.0), black),
+            (point(0.0, 0.0, 1.0), black),
+            (point(0.708, 0.0, 0.708), black),
+            (point(0.0, 5.0, 2.5), white),
+        ];
+
+        for (p, expected) in cases {
+            assert_eq!(pattern.pattern_at(p), expected);
+        }
+    }
+}
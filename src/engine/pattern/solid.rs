@@ -0,0 +1,58 @@
+use super::{Pattern, PatternData};
+use crate::elementary::point::Point;
+use crate::engine::color::Color;
+
+/// The same color everywhere; the building block other patterns are made
+/// from when given plain colors.
+#[derive(Debug, Clone)]
+pub struct Solid {
+    data: PatternData,
+    color: Color,
+}
+
+impl Solid {
+    pub fn new(color: Color) -> Solid {
+        Solid {
+            data: PatternData::new(),
+            color,
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        self.color
+    }
+}
+
+impl Pattern for Solid {
+    fn data(&self) -> &PatternData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut PatternData {
+        &mut self.data
+    }
+
+    fn pattern_at(&self, _point: Point) -> Color {
+        self.color
+    }
+}
+
+#[cfg(test)]
+mod solid_tests {
+    use super::Color;
+    use super::Pattern;
+    use super::Point;
+    use super::Solid;
+    use crate::elementary::float::Float;
+
+    #[test]
+    fn a_solid_pattern_is_the_same_everywhere() {
+        let red = Color::new(Float::new(1.0), Float::new(0.0), Float::new(0.0));
+        let pattern = Solid::new(red);
+
+        for (x, y, z) in [(0.0, 0.0, 0.0), (1.5, -2.5, 3.5), (-100.0, 7.0, 0.25)] {
+            let point = Point::new(Float::new(x), Float::new(y), Float::new(z));
+            assert_eq!(pattern.pattern_at(point), red);
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use super::{is_even, Pattern, PatternData, PatternPair};
+use crate::elementary::point::Point;
+use crate::engine::color::Color;
+
+/// Alternates between two colors in unit-wide bands along x.
+#[derive(Debug)]
+pub struct Stripe {
+    data: PatternData,
+    patterns: PatternPair,
+}
+
+impl Stripe {
+    pub fn new(a: Color, b: Color) -> Stripe {
+        Stripe::with_patterns(PatternPair::solid(a, b))
+    }
+
+    pub fn with_patterns(patterns: PatternPair) -> Stripe {
+        Stripe {
+            data: PatternData::new(),
+            patterns,
+        }
+    }
+
+    pub fn patterns(&self) -> &PatternPair {
+        &self.patterns
+    }
+}
+
+impl Pattern for Stripe {
+    fn data(&self) -> &PatternData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut PatternData {
+        &mut self.data
+    }
+
+    fn pattern_at(&self, point: Point) -> Color {
+        self.patterns.pick(is_even(point.x()), point)
+    }
+}
+
+#[cfg(test)]
+mod stripe_tests {
+    use super::Color;
+    use super::Pattern;
+    use super::Stripe;
+    use crate::elementary::float::Float;
+    use crate::test_helpers::point;
+
+    fn white() -> Color {
+        Color::new(Float::new(1.0), Float::new(1.0), Float::new(1.0))
+    }
+
+    fn black() -> Color {
+        Color::new(Float::new(0.0), Float::new(0.0), Float::new(0.0))
+    }
+
+    #[test]
+    fn creating_a_stripe_pattern() {
+        let pattern = Stripe::new(white(), black());
+
+        let origin = point(0.0, 0.0, 0.0);
+
+        assert_eq!(pattern.patterns().a().pattern_at(origin), white());
+        assert_eq!(pattern.patterns().b().pattern_at(origin), black());
+    }
+
+    #[test]
+    fn a_stripe_pattern_is_constant_in_y_and_z() {
+        let pattern = Stripe::new(white(), black());
+
+        for (y, z) in [(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (0.0, 1.0), (0.0, 2.0)] {
+            assert_eq!(pattern.pattern_at(point(0.0, y, z)), white());
+        }
+    }
+
+    #[test]
+    fn a_stripe_pattern_alternates_in_x() {
+        let pattern = Stripe::new(white(), black());
+        let cases = [
+            (0.0, white()),
+            (0.9, white()),
+            (1.0, black()),
+            (-0.1, black()),
+            (-1.0, black()),
+            (-1.1, white()),
+        ];
+
+        for (x, expected) in cases {
+            assert_eq!(pattern.pattern_at(point(x, 0.0, 0.0)), expected);
+        }
+    }
+}
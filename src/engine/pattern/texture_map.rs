@@ -0,0 +1,287 @@
+use super::mapping::{cube_map, CubeFace, UvMapping};
+use super::uv::UvPattern;
+use super::{Pattern, PatternData};
+use crate::elementary::point::Point;
+use crate::engine::color::Color;
+use crate::shape::Shape;
+
+/// Wraps a flat UV pattern onto a surface through one of the mappings.
+/// Shapes with texture coordinates of their own, such as mesh triangles
+/// read with `vt` records, are sampled at those instead.
+#[derive(Debug)]
+pub struct TextureMap {
+    data: PatternData,
+    uv_pattern: Box<dyn UvPattern>,
+    mapping: UvMapping,
+}
+
+impl TextureMap {
+    pub fn new<P: UvPattern + 'static>(uv_pattern: P, mapping: UvMapping) -> TextureMap {
+        TextureMap {
+            data: PatternData::new(),
+            uv_pattern: Box::new(uv_pattern),
+            mapping,
+        }
+    }
+
+    pub fn uv_pattern(&self) -> &dyn UvPattern {
+        self.uv_pattern.as_ref()
+    }
+
+    pub fn mapping(&self) -> UvMapping {
+        self.mapping
+    }
+}
+
+impl Pattern for TextureMap {
+    fn data(&self) -> &PatternData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut PatternData {
+        &mut self.data
+    }
+
+    fn pattern_at(&self, point: Point) -> Color {
+        let (u, v) = self.mapping.map(point);
+
+        self.uv_pattern.uv_pattern_at(u, v)
+    }
+
+    fn pattern_at_shape(&self, object: &dyn Shape, world_point: Point) -> Color {
+        let object_point = object.world_to_object(world_point);
+
+        match object.texture_coords_at(object_point) {
+            Some((u, v)) => self.uv_pattern.uv_pattern_at(u, v),
+            None => self.transformed_pattern_at(object_point),
+        }
+    }
+}
+
+/// Gives each face of a cube its own UV pattern. Every face starts out
+/// with the same one, and faces can then be replaced one at a time.
+#[derive(Debug)]
+pub struct CubeMap {
+    data: PatternData,
+    faces: Vec<Box<dyn UvPattern>>,
+}
+
+impl CubeMap {
+    pub fn new<P: UvPattern + Clone + 'static>(uv_pattern: P) -> CubeMap {
+        CubeMap {
+            data: PatternData::new(),
+            faces: (0..6)
+                .map(|_| Box::new(uv_pattern.clone()) as Box<dyn UvPattern>)
+                .collect(),
+        }
+    }
+
+    pub fn face(&self, face: CubeFace) -> &dyn UvPattern {
+        self.faces[face as usize].as_ref()
+    }
+
+    pub fn set_face<P: UvPattern + 'static>(&mut self, face: CubeFace, uv_pattern: P) {
+        self.faces[face as usize] = Box::new(uv_pattern);
+    }
+}
+
+impl Pattern for CubeMap {
+    fn data(&self) -> &PatternData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut PatternData {
+        &mut self.data
+    }
+
+    fn pattern_at(&self, point: Point) -> Color {
+        let (face, u, v) = cube_map(point);
+
+        self.face(face).uv_pattern_at(u, v)
+    }
+}
+
+#[cfg(test)]
+mod texture_map_tests {
+    use super::Color;
+    use super::CubeFace;
+    use super::CubeMap;
+    use super::Pattern;
+    use super::TextureMap;
+    use super::UvMapping;
+    use crate::elementary::float::Float;
+    use crate::elementary::matrix::Matrix4;
+    use crate::engine::pattern::uv::{AlignCheck, UvCheckers};
+    use crate::shape::sphere::Sphere;
+    use crate::shape::triangle::Triangle;
+    use crate::shape::Shape;
+    use crate::test_helpers::{color, point};
+
+    fn black() -> Color {
+        color(0.0, 0.0, 0.0)
+    }
+
+    fn white() -> Color {
+        color(1.0, 1.0, 1.0)
+    }
+
+    fn align_check(main: Color, ul: Color, ur: Color, bl: Color, br: Color) -> AlignCheck {
+        AlignCheck::new(main, ul, ur, bl, br)
+    }
+
+    #[test]
+    fn using_a_texture_map_pattern_with_a_spherical_map() {
+        let checkers = UvCheckers::new(Float::new(16.0), Float::new(8.0), black(), white());
+        let pattern = TextureMap::new(checkers, UvMapping::Spherical);
+
+        let cases = vec![
+            (point(0.4315, 0.4670, 0.7719), white()),
+            (point(-0.9654, 0.2552, -0.0534), black()),
+            (point(0.1039, 0.7090, 0.6975), white()),
+            (point(-0.4986, -0.7856, -0.3663), black()),
+            (point(-0.0317, -0.9395, 0.3411), black()),
+            (point(0.4809, -0.7721, 0.4154), black()),
+            (point(0.0285, -0.9612, -0.2745), black()),
+            (point(-0.5734, -0.2162, -0.7903), white()),
+            (point(0.7688, -0.1470, 0.6223), black()),
+            (point(-0.7652, 0.2175, 0.6060), black()),
+        ];
+
+        for (p, expected) in cases {
+            assert_eq!(pattern.pattern_at(p), expected);
+        }
+    }
+
+    #[test]
+    fn a_texture_map_goes_through_the_object_transform() {
+        let checkers = UvCheckers::new(Float::new(2.0), Float::new(2.0), black(), white());
+        let pattern = TextureMap::new(checkers, UvMapping::Planar);
+        let mut sphere = Sphere::new();
+        sphere.set_transform(Matrix4::scaling(
+            Float::new(2.0),
+            Float::new(2.0),
+            Float::new(2.0),
+        ));
+
+        assert_eq!(
+            pattern.pattern_at_shape(&sphere, point(0.5, 0.0, 0.5)),
+            black()
+        );
+        assert_eq!(
+            pattern.pattern_at_shape(&sphere, point(1.5, 0.0, 0.5)),
+            white()
+        );
+    }
+
+    #[test]
+    fn a_texture_map_prefers_the_shapes_own_texture_coordinates() {
+        let checkers = UvCheckers::new(Float::new(2.0), Float::new(2.0), black(), white());
+        let pattern = TextureMap::new(checkers, UvMapping::Planar);
+        let mut triangle = Triangle::new(
+            point(0.0, 0.0, 0.0),
+            point(1.0, 0.0, 0.0),
+            point(0.0, 0.0, 1.0),
+        );
+        let at = |u: f64, v: f64| (Float::new(u), Float::new(v));
+
+        // planar mapping alone would give black at this point
+        assert_eq!(
+            pattern.pattern_at_shape(&triangle, point(0.25, 0.0, 0.25)),
+            black()
+        );
+        triangle.set_texture_coords([at(0.5, 0.0), at(1.0, 0.0), at(0.5, 0.5)]);
+        assert_eq!(
+            pattern.pattern_at_shape(&triangle, point(0.25, 0.0, 0.25)),
+            white()
+        );
+    }
+
+    #[test]
+    fn finding_the_colors_on_a_mapped_cube() {
+        let red = color(1.0, 0.0, 0.0);
+        let yellow = color(1.0, 1.0, 0.0);
+        let brown = color(1.0, 0.5, 0.0);
+        let green = color(0.0, 1.0, 0.0);
+        let cyan = color(0.0, 1.0, 1.0);
+        let blue = color(0.0, 0.0, 1.0);
+        let purple = color(1.0, 0.0, 1.0);
+        let white = white();
+
+        let mut pattern = CubeMap::new(align_check(yellow, cyan, red, blue, brown));
+        pattern.set_face(
+            CubeFace::Front,
+            align_check(cyan, red, yellow, brown, green),
+        );
+        pattern.set_face(
+            CubeFace::Right,
+            align_check(red, yellow, purple, green, white),
+        );
+        pattern.set_face(
+            CubeFace::Back,
+            align_check(green, purple, cyan, white, blue),
+        );
+        pattern.set_face(CubeFace::Up, align_check(brown, cyan, purple, red, yellow));
+        pattern.set_face(
+            CubeFace::Down,
+            align_check(purple, brown, green, blue, white),
+        );
+
+        let cases = vec![
+            (point(-1.0, 0.0, 0.0), yellow),
+            (point(-1.0, 0.9, -0.9), cyan),
+            (point(-1.0, 0.9, 0.9), red),
+            (point(-1.0, -0.9, -0.9), blue),
+            (point(-1.0, -0.9, 0.9), brown),
+            (point(0.0, 0.0, 1.0), cyan),
+            (point(-0.9, 0.9, 1.0), red),
+            (point(0.9, 0.9, 1.0), yellow),
+            (point(-0.9, -0.9, 1.0), brown),
+            (point(0.9, -0.9, 1.0), green),
+            (point(1.0, 0.0, 0.0), red),
+            (point(1.0, 0.9, 0.9), yellow),
+            (point(1.0, 0.9, -0.9), purple),
+            (point(1.0, -0.9, 0.9), green),
+            (point(1.0, -0.9, -0.9), white),
+            (point(0.0, 0.0, -1.0), green),
+            (point(0.9, 0.9, -1.0), purple),
+            (point(-0.9, 0.9, -1.0), cyan),
+            (point(0.9, -0.9, -1.0), white),
+            (point(-0.9, -0.9, -1.0), blue),
+            (point(0.0, 1.0, 0.0), brown),
+            (point(-0.9, 1.0, -0.9), cyan),
+            (point(0.9, 1.0, -0.9), purple),
+            (point(-0.9, 1.0, 0.9), red),
+            (point(0.9, 1.0, 0.9), yellow),
+            (point(0.0, -1.0, 0.0), purple),
+            (point(-0.9, -1.0, 0.9), brown),
+            (point(0.9, -1.0, 0.9), green),
+            (point(-0.9, -1.0, -0.9), blue),
+            (point(0.9, -1.0, -0.9), white),
+        ];
+
+        for (p, expected) in cases {
+            assert_eq!(pattern.pattern_at(p), expected);
+        }
+    }
+
+    #[test]
+    fn a_cube_map_starts_with_the_same_pattern_on_every_face() {
+        let pattern = CubeMap::new(UvCheckers::new(
+            Float::new(2.0),
+            Float::new(2.0),
+            black(),
+            white(),
+        ));
+
+        for p in [
+            point(-0.5, -0.5, 1.0),
+            point(1.0, -0.5, 0.5),
+            point(0.5, -0.5, -1.0),
+            point(-1.0, -0.5, -0.5),
+            point(-0.5, 1.0, 0.5),
+            point(-0.5, -1.0, -0.5),
+        ] {
+            assert_eq!(pattern.pattern_at(p), black());
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use crate::elementary::float::Float;
+use crate::engine::canvas::Canvas;
+use crate::engine::color::Color;
+
+use std::fmt;
+
+/// A flat, two-dimensional pattern addressed by `u` and `v`, each running
+/// from 0 to 1 across the artwork. A mapping wraps it onto a 3D surface.
+pub trait UvPattern: fmt::Debug {
+    fn uv_pattern_at(&self, u: Float, v: Float) -> Color;
+}
+
+/// A checkerboard `width` squares across and `height` squares up.
+#[derive(Debug, Clone)]
+pub struct UvCheckers {
+    width: Float,
+    height: Float,
+    a: Color,
+    b: Color,
+}
+
+impl UvCheckers {
+    pub fn new(width: Float, height: Float, a: Color, b: Color) -> UvCheckers {
+        UvCheckers {
+            width,
+            height,
+            a,
+            b,
+        }
+    }
+
+    pub fn width(&self) -> Float {
+        self.width
+    }
+
+    pub fn height(&self) -> Float {
+        self.height
+    }
+}
+
+impl UvPattern for UvCheckers {
+    fn uv_pattern_at(&self, u: Float, v: Float) -> Color {
+        let u = (u * self.width).floor().value();
+        let v = (v * self.height).floor().value();
+
+        if (u + v).rem_euclid(2.0) == 0.0 {
+            self.a
+        } else {
+            self.b
+        }
+    }
+}
+
+/// A test card for checking how a mapping is oriented: one main color with
+/// a differently colored square in each corner.
+#[derive(Debug, Clone)]
+pub struct AlignCheck {
+    main: Color,
+    upper_left: Color,
+    upper_right: Color,
+    bottom_left: Color,
+    bottom_right: Color,
+}
+
+impl AlignCheck {
+    pub fn new(
+        main: Color,
+        upper_left: Color,
+        upper_right: Color,
+        bottom_left: Color,
+        bottom_right: Color,
+    ) -> AlignCheck {
+        AlignCheck {
+            main,
+            upper_left,
+            upper_right,
+            bottom_left,
+            bottom_right,
+        }
+    }
+}
+
+impl UvPattern for AlignCheck {
+    fn uv_pattern_at(&self, u: Float, v: Float) -> Color {
+        let (u, v) = (u.value(), v.value());
+
+        if v > 0.8 {
+            if u < 0.2 {
+                return self.upper_left;
+            }
+            if u > 0.8 {
+                return self.upper_right;
+            }
+        } else if v < 0.2 {
+            if u < 0.2 {
+                return self.bottom_left;
+            }
+            if u > 0.8 {
+                return self.bottom_right;
+            }
+        }
+
+        self.main
+    }
+}
+
+/// An image stretched over the whole of uv space, with `v` = 1 at the top
+/// row. Coordinates outside 0 to 1 repeat the image.
+#[derive(Debug, Clone)]
+pub struct UvImage {
+    canvas: Canvas,
+}
+
+impl UvImage {
+    pub fn new(canvas: Canvas) -> UvImage {
+        if canvas.width() == 0 || canvas.height() == 0 {
+            panic!("an image pattern needs at least one pixel");
+        }
+
+        UvImage { canvas }
+    }
+
+    pub fn canvas(&self) -> &Canvas {
+        &self.canvas
+    }
+}
+
+impl UvPattern for UvImage {
+    fn uv_pattern_at(&self, u: Float, v: Float) -> Color {
+        // 1 itself is the last column or row, not the start of the next copy
+        let wrap = |t: f64| {
+            if (0.0..=1.0).contains(&t) {
+                t
+            } else {
+                t.rem_euclid(1.0)
+            }
+        };
+        let u = wrap(u.value());
+        let v = 1.0 - wrap(v.value());
+
+        let x = u * (self.canvas.width() - 1) as f64;
+        let y = v * (self.canvas.height() - 1) as f64;
+
+        *self.canvas.pixel_at(x.round() as usize, y.round() as usize)
+    }
+}
+
+#[cfg(test)]
+mod uv_tests {
+    use super::AlignCheck;
+    use super::Float;
+    use super::UvCheckers;
+    use super::UvImage;
+    use super::UvPattern;
+    use crate::engine::canvas::Canvas;
+    use crate::test_helpers::color;
+
+    #[test]
+    fn checkers_in_uv_space() {
+        let black = color(0.0, 0.0, 0.0);
+        let white = color(1.0, 1.0, 1.0);
+        let pattern = UvCheckers::new(Float::new(2.0), Float::new(2.0), black, white);
+
+        let cases = vec![
+            (0.0, 0.0, black),
+            (0.5, 0.0, white),
+            (0.0, 0.5, white),
+            (0.5, 0.5, black),
+            (1.0, 1.0, black),
+        ];
+
+        for (u, v, expected) in cases {
+            assert_eq!(
+                pattern.uv_pattern_at(Float::new(u), Float::new(v)),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn align_check_marks_each_corner() {
+        let main = color(1.0, 1.0, 1.0);
+        let upper_left = color(1.0, 0.0, 0.0);
+        let upper_right = color(1.0, 1.0, 0.0);
+        let bottom_left = color(0.0, 1.0, 0.0);
+        let bottom_right = color(0.0, 1.0, 1.0);
+        let pattern = AlignCheck::new(main, upper_left, upper_right, bottom_left, bottom_right);
+
+        let cases = vec![
+            (0.5, 0.5, main),
+            (0.1, 0.9, upper_left),
+            (0.9, 0.9, upper_right),
+            (0.1, 0.1, bottom_left),
+            (0.9, 0.1, bottom_right),
+            (0.5, 0.9, main),
+            (0.1, 0.5, main),
+        ];
+
+        for (u, v, expected) in cases {
+            assert_eq!(
+                pattern.uv_pattern_at(Float::new(u), Float::new(v)),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn an_image_pattern_samples_its_canvas() {
+        // each pixel's value is its column plus its row, out of 20
+        let mut input = String::from("P3\n10 10\n20\n");
+        for y in 0..10 {
+            for x in 0..10 {
+                let value = x + y;
+                input.push_str(&format!("{} {} {} ", value, value, value));
+            }
+            input.push('\n');
+        }
+        let pattern = UvImage::new(Canvas::from_ppm(&input).unwrap());
+
+        let cases = vec![
+            (0.0, 0.0, color(0.45, 0.45, 0.45)),
+            (0.3, 0.0, color(0.6, 0.6, 0.6)),
+            (0.6, 0.3, color(0.55, 0.55, 0.55)),
+            (1.0, 1.0, color(0.45, 0.45, 0.45)),
+            (1.3, -1.0, color(0.6, 0.6, 0.6)),
+        ];
+
+        for (u, v, expected) in cases {
+            assert_eq!(
+                pattern.uv_pattern_at(Float::new(u), Float::new(v)),
+                expected
+            );
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use super::{Pattern, PatternData, PatternPair};
+use crate::elementary::float::Float;
+use crate::elementary::point::Point;
+use crate::engine::color::Color;
+use crate::noise::{fbm, Perlin};
+
+/// Growth rings around the y axis, one per unit of radius, made irregular
+/// by Perlin fBm. The strength is how far a ring can drift in radius.
+#[derive(Debug)]
+pub struct Wood {
+    data: PatternData,
+    patterns: PatternPair,
+    noise: Perlin,
+    octaves: usize,
+    strength: Float,
+}
+
+impl Wood {
+    pub fn new(a: Color, b: Color) -> Wood {
+        Wood::with_patterns(PatternPair::solid(a, b))
+    }
+
+    pub fn with_patterns(patterns: PatternPair) -> Wood {
+        Wood {
+            data: PatternData::new(),
+            patterns,
+            noise: Perlin::new(),
+            octaves: 3,
+            strength: Float::new(0.15),
+        }
+    }
+
+    pub fn patterns(&self) -> &PatternPair {
+        &self.patterns
+    }
+
+    pub fn set_seed(&mut self, seed: u64) {
+        self.noise = Perlin::with_seed(seed);
+    }
+
+    pub fn octaves(&self) -> usize {
+        self.octaves
+    }
+
+    pub fn set_octaves(&mut self, octaves: usize) {
+        self.octaves = octaves;
+    }
+
+    pub fn strength(&self) -> Float {
+        self.strength
+    }
+
+    pub fn set_strength(&mut self, strength: Float) {
+        self.strength = strength;
+    }
+}
+
+impl Pattern for Wood {
+    fn data(&self) -> &PatternData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut PatternData {
+        &mut self.data
+    }
+
+    fn pattern_at(&self, point: Point) -> Color {
+        let radius = (point.x() * point.x() + point.z() * point.z()).sqrt()
+            + fbm(&self.noise, point, self.octaves) * self.strength;
+        let fraction = radius - radius.floor();
+
+        self.patterns.mix(fraction, point)
+    }
+}
+
+#[cfg(test)]
+mod wood_tests {
+    use super::Float;
+    use super::Pattern;
+    use super::Point;
+    use super::Wood;
+    use crate::test_helpers::{color, point};
+
+    fn wood() -> Wood {
+        Wood::new(color(1.0, 1.0, 1.0), color(0.0, 0.0, 0.0))
+    }
+
+    fn samples() -> impl Iterator<Item = Point> {
+        (0..200).map(|i| point(i as f64 * 0.137, i as f64 * 0.291, i as f64 * 0.053))
+    }
+
+    #[test]
+    fn without_noise_wood_is_concentric_rings() {
+        let mut pattern = wood();
+        pattern.set_strength(Float::new(0.0));
+
+        let cases = vec![
+            (point(0.25, 0.0, 0.0), color(0.75, 0.75, 0.75)),
+            (point(0.0, 5.0, 0.5), color(0.5, 0.5, 0.5)),
+            (point(0.6, -2.0, 0.8), color(1.0, 1.0, 1.0)),
+            (point(-1.5, 0.0, 2.0), color(0.5, 0.5, 0.5)),
+        ];
+
+        for (p, expected) in cases {
+            assert_eq!(pattern.pattern_at(p), expected);
+        }
+    }
+
+    #[test]
+    fn noise_distorts_the_rings() {
+        let mut rings = wood();
+        rings.set_strength(Float::new(0.0));
+        let pattern = wood();
+
+        assert!(samples().any(|p| pattern.pattern_at(p) != rings.pattern_at(p)));
+    }
+
+    #[test]
+    fn wood_is_deterministic_for_a_seed() {
+        let mut first = wood();
+        first.set_seed(3);
+        let mut second = wood();
+        second.set_seed(3);
+        let other = wood();
+
+        assert!(samples().all(|p| first.pattern_at(p) == second.pattern_at(p)));
+        assert!(samples().any(|p| first.pattern_at(p) != other.pattern_at(p)));
+    }
+}
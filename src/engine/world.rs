@@ -0,0 +1,658 @@
+use super::color::Color;
+use super::light::{lighting, PointLight};
+use crate::elementary::float::Float;
+use crate::elementary::point::Point;
+use crate::intersection::{Computations, Intersections};
+use crate::ray::Ray;
+use crate::shape::bounds::BoundingBox;
+use crate::shape::bvh::Bvh;
+use crate::shape::Shape;
+
+use std::cell::OnceCell;
+
+/// The objects and lights of a scene. Rays are only tested against objects
+/// whose bounds they cross, using a bounding volume hierarchy that is
+/// rebuilt whenever the objects may have changed.
+#[derive(Debug)]
+pub struct World {
+    objects: Vec<Box<dyn Shape>>,
+    lights: Vec<PointLight>,
+    max_depth: usize,
+    bvh: OnceCell<Bvh>,
+}
+
+impl World {
+    /// How many times a ray may bounce before its contribution is dropped.
+    pub const DEFAULT_MAX_DEPTH: usize = 5;
+
+    pub fn new() -> World {
+        World {
+            objects: vec![],
+            lights: vec![],
+            max_depth: World::DEFAULT_MAX_DEPTH,
+            bvh: OnceCell::new(),
+        }
+    }
+
+    /// The `remaining` depth that rendering starts each camera ray with.
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    pub fn set_max_depth(&mut self, max_depth: usize) {
+        self.max_depth = max_depth;
+    }
+
+    pub fn objects(&self) -> &[Box<dyn Shape>] {
+        &self.objects
+    }
+
+    pub fn objects_mut(&mut self) -> &mut [Box<dyn Shape>] {
+        self.bvh.take();
+        &mut self.objects
+    }
+
+    pub fn add_object<S: Shape + 'static>(&mut self, object: S) {
+        self.objects.push(Box::new(object));
+        self.bvh.take();
+    }
+
+    pub fn lights(&self) -> &[PointLight] {
+        &self.lights
+    }
+
+    pub fn lights_mut(&mut self) -> &mut [PointLight] {
+        &mut self.lights
+    }
+
+    pub fn add_light(&mut self, light: PointLight) {
+        self.lights.push(light);
+    }
+
+    pub fn intersect_world(&self, ray: &Ray) -> Intersections<'_> {
+        Intersections::new(
+            self.bvh()
+                .candidates(ray)
+                .into_iter()
+                .flat_map(|index| self.objects[index].intersect(ray))
+                .collect(),
+        )
+    }
+
+    fn bvh(&self) -> &Bvh {
+        self.bvh.get_or_init(|| {
+            let bounds = self
+                .objects
+                .iter()
+                .map(|object| object.parent_space_bounds())
+                .collect::<Vec<BoundingBox>>();
+
+            Bvh::build(&bounds)
+        })
+    }
+
+    /// `remaining` limits how many more bounces the reflected ray may take,
+    /// so that facing mirrors do not recurse forever.
+    pub fn shade_hit(&self, comps: &Computations, remaining: usize) -> Color {
+        let surface = self.lights.iter().fold(
+            Color::new(Float::new(0.0), Float::new(0.0), Float::new(0.0)),
+            |color, light| {
+                color
+                    + lighting(
+                        comps.object().material(),
+                        comps.object(),
+                        light,
+                        comps.over_point(),
+                        comps.eyev(),
+                        comps.normalv(),
+                        self.is_shadowed(comps.over_point(), light),
+                    )
+            },
+        );
+
+        let reflected = self.reflected_color(comps, remaining);
+        let refracted = self.refracted_color(comps, remaining);
+
+        let material = comps.object().material();
+        if material.reflective() > Float::new(0.0) && material.transparency() > Float::new(0.0) {
+            let reflectance = comps.schlick();
+            return surface + reflected * reflectance + refracted * (Float::new(1.0) - reflectance);
+        }
+
+        surface + reflected + refracted
+    }
+
+    /// The color seen along the bounced ray, scaled by how reflective the
+    /// surface is.
+    pub fn reflected_color(&self, comps: &Computations, remaining: usize) -> Color {
+        let reflective = comps.object().material().reflective();
+        if remaining == 0 || reflective == Float::new(0.0) {
+            return Color::new(Float::new(0.0), Float::new(0.0), Float::new(0.0));
+        }
+
+        let reflect_ray = Ray::new(comps.over_point(), comps.reflectv());
+
+        self.color_at(&reflect_ray, remaining - 1) * reflective
+    }
+
+    /// The color seen through the surface along the ray bent by Snell's law,
+    /// scaled by how transparent the surface is. Nothing comes through when
+    /// the angle is steep enough for total internal reflection.
+    pub fn refracted_color(&self, comps: &Computations, remaining: usize) -> Color {
+        let black = Color::new(Float::new(0.0), Float::new(0.0), Float::new(0.0));
+        let transparency = comps.object().material().transparency();
+        if remaining == 0 || transparency == Float::new(0.0) {
+            return black;
+        }
+
+        let n_ratio = comps.n1() / comps.n2();
+        let cos_i = comps.eyev().dot(comps.normalv());
+        let sin2_t = n_ratio * n_ratio * (Float::new(1.0) - cos_i * cos_i);
+        if sin2_t > Float::new(1.0) {
+            return black;
+        }
+
+        let cos_t = (Float::new(1.0) - sin2_t).sqrt();
+        let direction = comps.normalv() * (n_ratio * cos_i - cos_t) - comps.eyev() * n_ratio;
+        let refract_ray = Ray::new(comps.under_point(), direction);
+
+        self.color_at(&refract_ray, remaining - 1) * transparency
+    }
+
+    /// Casts a ray from `point` towards `light` and reports whether any
+    /// object sits between the two.
+    pub fn is_shadowed(&self, point: Point, light: &PointLight) -> bool {
+        let v = light.position() - point;
+        let distance = v.magnitude();
+        let ray = Ray::new(point, v.normalize());
+
+        match self.intersect_world(&ray).hit() {
+            Some(hit) => hit.t() < distance,
+            None => false,
+        }
+    }
+
+    pub fn color_at(&self, ray: &Ray, remaining: usize) -> Color {
+        let xs = self.intersect_world(ray);
+        match xs.hit() {
+            Some(hit) => self.shade_hit(&hit.prepare_computations_with(ray, &xs), remaining),
+            None => Color::new(Float::new(0.0), Float::new(0.0), Float::new(0.0)),
+        }
+    }
+}
+
+impl Default for World {
+    fn default() -> Self {
+        World::new()
+    }
+}
+
+#[cfg(test)]
+mod world_tests {
+    use super::Float;
+    use super::PointLight;
+    use super::Ray;
+    use super::Shape;
+    use super::World;
+    use crate::elementary::matrix::Matrix4;
+    use crate::engine::material::Material;
+    use crate::intersection::{Intersection, Intersections};
+    use crate::shape::plane::Plane;
+    use crate::shape::sphere::Sphere;
+    use crate::test_helpers::{color, point, vector};
+    use std::f64::consts::{FRAC_1_SQRT_2, PI, SQRT_2};
+
+    fn default_world() -> World {
+        let mut world = World::new();
+        world.add_light(PointLight::new(
+            point(-10.0, 10.0, -10.0),
+            color(1.0, 1.0, 1.0),
+        ));
+
+        let mut outer = Sphere::new();
+        let mut material = Material::default();
+        material.set_color(color(0.8, 1.0, 0.6));
+        material.set_diffuse(Float::new(0.7));
+        material.set_specular(Float::new(0.2));
+        outer.set_material(material);
+        world.add_object(outer);
+
+        let mut inner = Sphere::new();
+        inner.set_transform(Matrix4::scaling(
+            Float::new(0.5),
+            Float::new(0.5),
+            Float::new(0.5),
+        ));
+        world.add_object(inner);
+
+        world
+    }
+
+    #[test]
+    fn can_create_an_empty_world() {
+        let world = World::new();
+
+        assert!(world.objects().is_empty());
+        assert!(world.lights().is_empty());
+        assert_eq!(world.max_depth(), World::DEFAULT_MAX_DEPTH);
+    }
+
+    #[test]
+    fn can_intersect_a_world_with_a_ray() {
+        let world = default_world();
+        let ray = Ray::new(point(0.0, 0.0, -5.0), vector(0.0, 0.0, 1.0));
+        let xs = world.intersect_world(&ray);
+
+        assert_eq!(xs.len(), 4);
+        assert_eq!(xs[0].t(), Float::new(4.0));
+        assert_eq!(xs[1].t(), Float::new(4.5));
+        assert_eq!(xs[2].t(), Float::new(5.5));
+        assert_eq!(xs[3].t(), Float::new(6.0));
+    }
+
+    #[test]
+    fn can_shade_an_intersection() {
+        let world = default_world();
+        let ray = Ray::new(point(0.0, 0.0, -5.0), vector(0.0, 0.0, 1.0));
+        let shape = world.objects()[0].as_ref();
+        let comps = Intersection::new(Float::new(4.0), shape).prepare_computations(&ray);
+
+        assert_eq!(
+            world.shade_hit(&comps, World::DEFAULT_MAX_DEPTH),
+            color(0.38066, 0.47583, 0.2855)
+        );
+    }
+
+    #[test]
+    fn can_shade_an_intersection_from_the_inside() {
+        let mut world = default_world();
+        world.lights_mut()[0] = PointLight::new(point(0.0, 0.25, 0.0), color(1.0, 1.0, 1.0));
+        let ray = Ray::new(point(0.0, 0.0, 0.0), vector(0.0, 0.0, 1.0));
+        let shape = world.objects()[1].as_ref();
+        let comps = Intersection::new(Float::new(0.5), shape).prepare_computations(&ray);
+
+        assert_eq!(
+            world.shade_hit(&comps, World::DEFAULT_MAX_DEPTH),
+            color(0.90498, 0.90498, 0.90498)
+        );
+    }
+
+    #[test]
+    fn color_when_a_ray_misses() {
+        let world = default_world();
+        let ray = Ray::new(point(0.0, 0.0, -5.0), vector(0.0, 1.0, 0.0));
+
+        assert_eq!(
+            world.color_at(&ray, World::DEFAULT_MAX_DEPTH),
+            color(0.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn color_when_a_ray_hits() {
+        let world = default_world();
+        let ray = Ray::new(point(0.0, 0.0, -5.0), vector(0.0, 0.0, 1.0));
+
+        assert_eq!(
+            world.color_at(&ray, World::DEFAULT_MAX_DEPTH),
+            color(0.38066, 0.47583, 0.2855)
+        );
+    }
+
+    #[test]
+    fn color_with_an_intersection_behind_the_ray() {
+        let mut world = default_world();
+        for object in world.objects_mut() {
+            let mut material = object.material().clone();
+            material.set_ambient(Float::new(1.0));
+            object.set_material(material);
+        }
+        let ray = Ray::new(point(0.0, 0.0, 0.75), vector(0.0, 0.0, -1.0));
+        let inner_color = world.objects()[1].material().color();
+
+        assert_eq!(world.color_at(&ray, World::DEFAULT_MAX_DEPTH), inner_color);
+    }
+
+    #[test]
+    fn no_shadow_when_nothing_is_collinear_with_point_and_light() {
+        let world = default_world();
+
+        assert!(!world.is_shadowed(point(0.0, 10.0, 0.0), &world.lights()[0]));
+    }
+
+    #[test]
+    fn shadow_when_an_object_is_between_the_point_and_the_light() {
+        let world = default_world();
+
+        assert!(world.is_shadowed(point(10.0, -10.0, 10.0), &world.lights()[0]));
+    }
+
+    #[test]
+    fn no_shadow_when_an_object_is_behind_the_light() {
+        let world = default_world();
+
+        assert!(!world.is_shadowed(point(-20.0, 20.0, -20.0), &world.lights()[0]));
+    }
+
+    #[test]
+    fn no_shadow_when_an_object_is_behind_the_point() {
+        let world = default_world();
+
+        assert!(!world.is_shadowed(point(-2.0, 2.0, -2.0), &world.lights()[0]));
+    }
+
+    #[test]
+    fn can_shade_an_intersection_in_shadow() {
+        let mut world = World::new();
+        world.add_light(PointLight::new(
+            point(0.0, 0.0, -10.0),
+            color(1.0, 1.0, 1.0),
+        ));
+        world.add_object(Sphere::new());
+        let mut second = Sphere::new();
+        second.set_transform(Matrix4::translation(
+            Float::new(0.0),
+            Float::new(0.0),
+            Float::new(10.0),
+        ));
+        world.add_object(second);
+        let ray = Ray::new(point(0.0, 0.0, 5.0), vector(0.0, 0.0, 1.0));
+        let comps = Intersection::new(Float::new(4.0), world.objects()[1].as_ref())
+            .prepare_computations(&ray);
+
+        assert_eq!(
+            world.shade_hit(&comps, World::DEFAULT_MAX_DEPTH),
+            color(0.1, 0.1, 0.1)
+        );
+    }
+
+    #[test]
+    fn intersections_follow_objects_moved_after_a_render() {
+        let mut world = World::new();
+        world.add_object(Sphere::new());
+        world.add_object(Plane::new());
+        let ray = Ray::new(point(0.0, 5.0, -5.0), vector(0.0, 0.0, 1.0));
+
+        assert!(world.intersect_world(&ray).is_empty());
+
+        world.objects_mut()[0].set_transform(Matrix4::translation(
+            Float::new(0.0),
+            Float::new(5.0),
+            Float::new(0.0),
+        ));
+
+        assert_eq!(world.intersect_world(&ray).len(), 2);
+    }
+
+    /// A plane one unit below the origin, reflective enough to show the
+    /// default world's spheres.
+    fn reflective_plane() -> Plane {
+        let mut plane = Plane::new();
+        let mut material = Material::default();
+        material.set_reflective(Float::new(0.5));
+        plane.set_material(material);
+        plane.set_transform(Matrix4::translation(
+            Float::new(0.0),
+            Float::new(-1.0),
+            Float::new(0.0),
+        ));
+
+        plane
+    }
+
+    #[test]
+    fn reflected_color_for_a_nonreflective_material() {
+        let mut world = default_world();
+        let mut material = world.objects()[1].material().clone();
+        material.set_ambient(Float::new(1.0));
+        world.objects_mut()[1].set_material(material);
+        let ray = Ray::new(point(0.0, 0.0, 0.0), vector(0.0, 0.0, 1.0));
+        let comps = Intersection::new(Float::new(1.0), world.objects()[1].as_ref())
+            .prepare_computations(&ray);
+
+        assert_eq!(
+            world.reflected_color(&comps, World::DEFAULT_MAX_DEPTH),
+            color(0.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn reflected_color_for_a_reflective_material() {
+        let mut world = default_world();
+        world.add_object(reflective_plane());
+        let ray = Ray::new(
+            point(0.0, 0.0, -3.0),
+            vector(0.0, -FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+        );
+        let comps = Intersection::new(Float::new(SQRT_2), world.objects()[2].as_ref())
+            .prepare_computations(&ray);
+
+        assert_eq!(
+            world.reflected_color(&comps, World::DEFAULT_MAX_DEPTH),
+            color(0.19033, 0.23791, 0.14274)
+        );
+    }
+
+    #[test]
+    fn shade_hit_with_a_reflective_material() {
+        let mut world = default_world();
+        world.add_object(reflective_plane());
+        let ray = Ray::new(
+            point(0.0, 0.0, -3.0),
+            vector(0.0, -FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+        );
+        let comps = Intersection::new(Float::new(SQRT_2), world.objects()[2].as_ref())
+            .prepare_computations(&ray);
+
+        assert_eq!(
+            world.shade_hit(&comps, World::DEFAULT_MAX_DEPTH),
+            color(0.87676, 0.92434, 0.82917)
+        );
+    }
+
+    #[test]
+    fn color_at_with_mutually_reflective_surfaces() {
+        let mut world = World::new();
+        world.add_light(PointLight::new(point(0.0, 0.0, 0.0), color(1.0, 1.0, 1.0)));
+        let mut material = Material::default();
+        material.set_reflective(Float::new(1.0));
+        for y in [-1.0, 1.0] {
+            let mut plane = Plane::new();
+            plane.set_material(material.clone());
+            plane.set_transform(Matrix4::translation(
+                Float::new(0.0),
+                Float::new(y),
+                Float::new(0.0),
+            ));
+            world.add_object(plane);
+        }
+        let ray = Ray::new(point(0.0, 0.0, 0.0), vector(0.0, 1.0, 0.0));
+
+        // terminates, with each of the bounces adding some light
+        let color = world.color_at(&ray, World::DEFAULT_MAX_DEPTH);
+        assert!(color.r() > Float::new(1.0));
+    }
+
+    #[test]
+    fn reflected_color_at_the_maximum_recursive_depth() {
+        let mut world = default_world();
+        world.add_object(reflective_plane());
+        let ray = Ray::new(
+            point(0.0, 0.0, -3.0),
+            vector(0.0, -FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+        );
+        let comps = Intersection::new(Float::new(SQRT_2), world.objects()[2].as_ref())
+            .prepare_computations(&ray);
+
+        assert_eq!(world.reflected_color(&comps, 0), color(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn refracted_color_with_an_opaque_surface() {
+        let world = default_world();
+        let ray = Ray::new(point(0.0, 0.0, -5.0), vector(0.0, 0.0, 1.0));
+        let shape = world.objects()[0].as_ref();
+        let xs = Intersections::new(vec![
+            Intersection::new(Float::new(4.0), shape),
+            Intersection::new(Float::new(6.0), shape),
+        ]);
+        let comps = xs[0].prepare_computations_with(&ray, &xs);
+
+        assert_eq!(
+            world.refracted_color(&comps, World::DEFAULT_MAX_DEPTH),
+            color(0.0, 0.0, 0.0)
+        );
+    }
+
+    /// The default world with its outer sphere turned to glass.
+    fn glass_default_world() -> World {
+        let mut world = default_world();
+        let mut material = world.objects()[0].material().clone();
+        material.set_transparency(Float::new(1.0));
+        material.set_refractive_index(Float::new(1.5));
+        world.objects_mut()[0].set_material(material);
+
+        world
+    }
+
+    #[test]
+    fn refracted_color_at_the_maximum_recursive_depth() {
+        let world = glass_default_world();
+        let ray = Ray::new(point(0.0, 0.0, -5.0), vector(0.0, 0.0, 1.0));
+        let shape = world.objects()[0].as_ref();
+        let xs = Intersections::new(vec![
+            Intersection::new(Float::new(4.0), shape),
+            Intersection::new(Float::new(6.0), shape),
+        ]);
+        let comps = xs[0].prepare_computations_with(&ray, &xs);
+
+        assert_eq!(world.refracted_color(&comps, 0), color(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn refracted_color_under_total_internal_reflection() {
+        let world = glass_default_world();
+        let ray = Ray::new(point(0.0, 0.0, FRAC_1_SQRT_2), vector(0.0, 1.0, 0.0));
+        let shape = world.objects()[0].as_ref();
+        let xs = Intersections::new(vec![
+            Intersection::new(Float::new(-FRAC_1_SQRT_2), shape),
+            Intersection::new(Float::new(FRAC_1_SQRT_2), shape),
+        ]);
+        // inside the sphere, so look at the second intersection
+        let comps = xs[1].prepare_computations_with(&ray, &xs);
+
+        assert_eq!(
+            world.refracted_color(&comps, World::DEFAULT_MAX_DEPTH),
+            color(0.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn refracted_color_passes_through_to_the_scene_behind() {
+        let mut world = World::new();
+        world.add_light(PointLight::new(
+            point(-10.0, 10.0, -10.0),
+            color(1.0, 1.0, 1.0),
+        ));
+        let mut pane = Plane::new();
+        let mut glass = Material::default();
+        glass.set_transparency(Float::new(1.0));
+        glass.set_refractive_index(Float::new(1.5));
+        glass.set_ambient(Float::new(0.0));
+        glass.set_diffuse(Float::new(0.0));
+        glass.set_specular(Float::new(0.0));
+        pane.set_material(glass);
+        pane.set_transform(Matrix4::rotation_x(Float::new(PI / 2.0)));
+        world.add_object(pane);
+        let mut backdrop = Plane::new();
+        let mut material = Material::default();
+        material.set_color(color(1.0, 0.0, 0.0));
+        material.set_ambient(Float::new(1.0));
+        material.set_diffuse(Float::new(0.0));
+        material.set_specular(Float::new(0.0));
+        backdrop.set_material(material);
+        backdrop.set_transform(Matrix4::rotation_x(Float::new(PI / 2.0)).translate(
+            Float::new(0.0),
+            Float::new(0.0),
+            Float::new(5.0),
+        ));
+        world.add_object(backdrop);
+        let ray = Ray::new(point(0.0, 0.0, -5.0), vector(0.0, 0.0, 1.0));
+
+        // a pane seen head on does not bend the ray, so the backdrop shows
+        assert_eq!(
+            world.color_at(&ray, World::DEFAULT_MAX_DEPTH),
+            color(1.0, 0.0, 0.0)
+        );
+    }
+
+    /// The default world with a glass floor below it and a red ball under
+    /// the floor.
+    fn world_with_glass_floor(reflective: f64) -> World {
+        let mut world = default_world();
+        let mut floor = Plane::new();
+        floor.set_transform(Matrix4::translation(
+            Float::new(0.0),
+            Float::new(-1.0),
+            Float::new(0.0),
+        ));
+        let mut material = Material::default();
+        material.set_reflective(Float::new(reflective));
+        material.set_transparency(Float::new(0.5));
+        material.set_refractive_index(Float::new(1.5));
+        floor.set_material(material);
+        world.add_object(floor);
+
+        let mut ball = Sphere::new();
+        let mut material = Material::default();
+        material.set_color(color(1.0, 0.0, 0.0));
+        material.set_ambient(Float::new(0.5));
+        ball.set_material(material);
+        ball.set_transform(Matrix4::translation(
+            Float::new(0.0),
+            Float::new(-3.5),
+            Float::new(-0.5),
+        ));
+        world.add_object(ball);
+
+        world
+    }
+
+    #[test]
+    fn shade_hit_with_a_transparent_material() {
+        let world = world_with_glass_floor(0.0);
+        let ray = Ray::new(
+            point(0.0, 0.0, -3.0),
+            vector(0.0, -FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+        );
+        let xs = Intersections::new(vec![Intersection::new(
+            Float::new(SQRT_2),
+            world.objects()[2].as_ref(),
+        )]);
+        let comps = xs[0].prepare_computations_with(&ray, &xs);
+
+        assert_eq!(
+            world.shade_hit(&comps, World::DEFAULT_MAX_DEPTH),
+            color(0.93642, 0.68642, 0.68642)
+        );
+    }
+
+    #[test]
+    fn shade_hit_with_a_reflective_transparent_material() {
+        let world = world_with_glass_floor(0.5);
+        let ray = Ray::new(
+            point(0.0, 0.0, -3.0),
+            vector(0.0, -FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+        );
+        let xs = Intersections::new(vec![Intersection::new(
+            Float::new(SQRT_2),
+            world.objects()[2].as_ref(),
+        )]);
+        let comps = xs[0].prepare_computations_with(&ray, &xs);
+
+        assert_eq!(
+            world.shade_hit(&comps, World::DEFAULT_MAX_DEPTH),
+            color(0.93391, 0.69643, 0.69243)
+        );
+    }
+}
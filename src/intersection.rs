@@ -0,0 +1,552 @@
+use crate::elementary::float::Float;
+use crate::elementary::point::Point;
+use crate::elementary::vector::Vector;
+use crate::ray::Ray;
+use crate::shape::Shape;
+
+use std::ops;
+
+#[derive(Debug, Copy, Clone)]
+pub struct Intersection<'a> {
+    t: Float,
+    object: &'a dyn Shape,
+    u: Float,
+    v: Float,
+}
+
+impl<'a> Intersection<'a> {
+    pub fn new(t: Float, object: &'a dyn Shape) -> Intersection<'a> {
+        Intersection::with_uv(t, object, Float::new(0.0), Float::new(0.0))
+    }
+
+    /// Records where on a triangle the hit landed, in barycentric `u`/`v`
+    /// coordinates relative to its second and third vertices.
+    pub fn with_uv(t: Float, object: &'a dyn Shape, u: Float, v: Float) -> Intersection<'a> {
+        Intersection { t, object, u, v }
+    }
+
+    pub fn t(&self) -> Float {
+        self.t
+    }
+
+    pub fn object(&self) -> &'a dyn Shape {
+        self.object
+    }
+
+    pub fn u(&self) -> Float {
+        self.u
+    }
+
+    pub fn v(&self) -> Float {
+        self.v
+    }
+
+    /// Prepares the hit as if it were the only intersection along the ray,
+    /// so the ray is taken to enter its object from empty space.
+    pub fn prepare_computations(&self, ray: &Ray) -> Computations<'a> {
+        self.prepare_computations_with(ray, &Intersections::new(vec![*self]))
+    }
+
+    /// Prepares the hit given every intersection along the ray, which is
+    /// needed to know which materials the ray is leaving and entering.
+    pub fn prepare_computations_with(&self, ray: &Ray, xs: &Intersections<'a>) -> Computations<'a> {
+        let point = ray.position(self.t);
+        let eyev = -ray.direction();
+        let mut normalv = self.object.normal_at(point, self);
+
+        let inside = normalv.dot(eyev) < Float::new(0.0);
+        if inside {
+            normalv = -normalv;
+        }
+
+        let (n1, n2) = self.refractive_indices(xs);
+
+        Computations {
+            t: self.t,
+            object: self.object,
+            point,
+            eyev,
+            normalv,
+            inside,
+            over_point: point + normalv * Float::EPSILON,
+            under_point: point - normalv * Float::EPSILON,
+            reflectv: ray.direction().reflect(normalv),
+            n1,
+            n2,
+        }
+    }
+
+    /// Walks the intersections up to this one, tracking which objects the
+    /// ray is inside, to find the refractive index on either side of it.
+    fn refractive_indices(&self, xs: &Intersections<'a>) -> (Float, Float) {
+        let outermost = |containers: &[&dyn Shape]| match containers.last() {
+            Some(object) => object.material().refractive_index(),
+            None => Float::new(1.0),
+        };
+
+        let mut containers: Vec<&dyn Shape> = vec![];
+        let mut n1 = Float::new(1.0);
+
+        for intersection in xs.iter() {
+            if intersection == self {
+                n1 = outermost(&containers);
+            }
+
+            match containers
+                .iter()
+                .position(|object| std::ptr::addr_eq(*object, intersection.object))
+            {
+                Some(index) => {
+                    containers.remove(index);
+                }
+                None => containers.push(intersection.object),
+            }
+
+            if intersection == self {
+                return (n1, outermost(&containers));
+            }
+        }
+
+        (n1, outermost(&containers))
+    }
+}
+
+/// The state of a hit that shading needs, computed once per intersection.
+#[derive(Debug, Copy, Clone)]
+pub struct Computations<'a> {
+    t: Float,
+    object: &'a dyn Shape,
+    point: Point,
+    eyev: Vector,
+    normalv: Vector,
+    inside: bool,
+    over_point: Point,
+    under_point: Point,
+    reflectv: Vector,
+    n1: Float,
+    n2: Float,
+}
+
+impl<'a> Computations<'a> {
+    pub fn t(&self) -> Float {
+        self.t
+    }
+
+    pub fn object(&self) -> &'a dyn Shape {
+        self.object
+    }
+
+    pub fn point(&self) -> Point {
+        self.point
+    }
+
+    pub fn eyev(&self) -> Vector {
+        self.eyev
+    }
+
+    pub fn normalv(&self) -> Vector {
+        self.normalv
+    }
+
+    pub fn inside(&self) -> bool {
+        self.inside
+    }
+
+    /// The hit point nudged along the normal so that rays cast from it do
+    /// not immediately hit the same surface again.
+    pub fn over_point(&self) -> Point {
+        self.over_point
+    }
+
+    /// The hit point nudged below the surface, where refracted rays start.
+    pub fn under_point(&self) -> Point {
+        self.under_point
+    }
+
+    /// The direction a ray bounces off the surface in.
+    pub fn reflectv(&self) -> Vector {
+        self.reflectv
+    }
+
+    /// The refractive index of the material the ray is leaving.
+    pub fn n1(&self) -> Float {
+        self.n1
+    }
+
+    /// The refractive index of the material the ray is entering.
+    pub fn n2(&self) -> Float {
+        self.n2
+    }
+
+    /// Schlick's approximation of the Fresnel effect: the fraction of light
+    /// reflected rather than refracted, which grows at grazing angles.
+    pub fn schlick(&self) -> Float {
+        let one = Float::new(1.0);
+        let mut cos = self.eyev.dot(self.normalv);
+
+        if self.n1 > self.n2 {
+            let n = self.n1 / self.n2;
+            let sin2_t = n * n * (one - cos * cos);
+            if sin2_t > one {
+                return one;
+            }
+
+            cos = (one - sin2_t).sqrt();
+        }
+
+        let r0 = ((self.n1 - self.n2) / (self.n1 + self.n2)).powf(Float::new(2.0));
+
+        r0 + (one - r0) * (one - cos).powf(Float::new(5.0))
+    }
+}
+
+impl PartialEq for Intersection<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.t == other.t && std::ptr::addr_eq(self.object, other.object)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Intersections<'a>(Vec<Intersection<'a>>);
+
+impl<'a> Intersections<'a> {
+    pub fn new(mut data: Vec<Intersection<'a>>) -> Intersections<'a> {
+        data.sort_by(|a, b| a.t().value().total_cmp(&b.t().value()));
+        Intersections(data)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Intersection<'a>> {
+        self.0.iter()
+    }
+
+    pub fn hit(&self) -> Option<&Intersection<'a>> {
+        // compare exactly: a surface a ray was just cast from sits a hair
+        // behind it, within the tolerance Float equality allows
+        self.0.iter().find(|i| i.t().value() >= 0.0)
+    }
+}
+
+impl<'a> IntoIterator for Intersections<'a> {
+    type Item = Intersection<'a>;
+    type IntoIter = std::vec::IntoIter<Intersection<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> ops::Index<usize> for Intersections<'a> {
+    type Output = Intersection<'a>;
+
+    fn index(&self, index: usize) -> &Intersection<'a> {
+        &self.0[index]
+    }
+}
+
+#[cfg(test)]
+mod intersection_tests {
+    use super::Float;
+    use super::Intersection;
+    use super::Intersections;
+    use super::Ray;
+    use super::Shape;
+    use crate::elementary::matrix::Matrix4;
+    use crate::engine::material::Material;
+    use crate::shape::plane::Plane;
+    use crate::shape::sphere::Sphere;
+    use crate::test_helpers::{point, vector};
+    use std::f64::consts::{FRAC_1_SQRT_2, SQRT_2};
+
+    fn glass_sphere() -> Sphere {
+        let mut sphere = Sphere::new();
+        let mut material = Material::default();
+        material.set_transparency(Float::new(1.0));
+        material.set_refractive_index(Float::new(1.5));
+        sphere.set_material(material);
+
+        sphere
+    }
+
+    #[test]
+    fn can_create_an_intersection() {
+        let sphere = Sphere::new();
+        let intersection = Intersection::new(Float::new(3.5), &sphere);
+
+        assert_eq!(intersection.t(), Float::new(3.5));
+        assert!(std::ptr::addr_eq(intersection.object(), &sphere));
+    }
+
+    #[test]
+    fn can_aggregate_intersections() {
+        let sphere = Sphere::new();
+        let i1 = Intersection::new(Float::new(1.0), &sphere);
+        let i2 = Intersection::new(Float::new(2.0), &sphere);
+        let xs = Intersections::new(vec![i2, i1]);
+
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t(), Float::new(1.0));
+        assert_eq!(xs[1].t(), Float::new(2.0));
+    }
+
+    #[test]
+    fn hit_when_all_intersections_have_positive_t() {
+        let sphere = Sphere::new();
+        let i1 = Intersection::new(Float::new(1.0), &sphere);
+        let i2 = Intersection::new(Float::new(2.0), &sphere);
+        let xs = Intersections::new(vec![i2, i1]);
+
+        assert_eq!(xs.hit(), Some(&i1));
+    }
+
+    #[test]
+    fn hit_when_some_intersections_have_negative_t() {
+        let sphere = Sphere::new();
+        let i1 = Intersection::new(Float::new(-1.0), &sphere);
+        let i2 = Intersection::new(Float::new(1.0), &sphere);
+        let xs = Intersections::new(vec![i2, i1]);
+
+        assert_eq!(xs.hit(), Some(&i2));
+    }
+
+    #[test]
+    fn hit_when_all_intersections_have_negative_t() {
+        let sphere = Sphere::new();
+        let i1 = Intersection::new(Float::new(-2.0), &sphere);
+        let i2 = Intersection::new(Float::new(-1.0), &sphere);
+        let xs = Intersections::new(vec![i2, i1]);
+
+        assert_eq!(xs.hit(), None);
+    }
+
+    #[test]
+    fn hit_skips_intersections_just_behind_the_ray() {
+        let sphere = Sphere::new();
+        let i1 = Intersection::new(Float::new(-0.000005), &sphere);
+        let i2 = Intersection::new(Float::new(2.0), &sphere);
+        let xs = Intersections::new(vec![i1, i2]);
+
+        assert_eq!(xs.hit(), Some(&i2));
+    }
+
+    #[test]
+    fn hit_is_always_the_lowest_nonnegative_intersection() {
+        let sphere = Sphere::new();
+        let i1 = Intersection::new(Float::new(5.0), &sphere);
+        let i2 = Intersection::new(Float::new(7.0), &sphere);
+        let i3 = Intersection::new(Float::new(-3.0), &sphere);
+        let i4 = Intersection::new(Float::new(2.0), &sphere);
+        let xs = Intersections::new(vec![i1, i2, i3, i4]);
+
+        assert_eq!(xs.hit(), Some(&i4));
+    }
+
+    #[test]
+    fn sorting_many_nearly_equal_intersections() {
+        // closely spaced values make the tolerant Float ordering
+        // intransitive, which the standard sort must not be handed
+        let sphere = Sphere::new();
+        let mut state = 0x2545_f491_u64;
+        for len in (20..220).step_by(10) {
+            let data = (0..len)
+                .map(|_| {
+                    state = state
+                        .wrapping_mul(6_364_136_223_846_793_005)
+                        .wrapping_add(1);
+                    Intersection::new(Float::new((state >> 40) as f64 * 1e-11), &sphere)
+                })
+                .collect();
+            let xs = Intersections::new(data);
+
+            assert_eq!(xs.len(), len);
+            assert!(xs
+                .iter()
+                .zip(xs.iter().skip(1))
+                .all(|(a, b)| a.t().value() <= b.t().value()));
+        }
+    }
+
+    #[test]
+    fn can_precompute_the_state_of_an_intersection() {
+        let ray = Ray::new(point(0.0, 0.0, -5.0), vector(0.0, 0.0, 1.0));
+        let sphere = Sphere::new();
+        let intersection = Intersection::new(Float::new(4.0), &sphere);
+        let comps = intersection.prepare_computations(&ray);
+
+        assert_eq!(comps.t(), intersection.t());
+        assert!(std::ptr::addr_eq(comps.object(), &sphere));
+        assert_eq!(comps.point(), point(0.0, 0.0, -1.0));
+        assert_eq!(comps.eyev(), vector(0.0, 0.0, -1.0));
+        assert_eq!(comps.normalv(), vector(0.0, 0.0, -1.0));
+    }
+
+    #[test]
+    fn hit_when_an_intersection_occurs_on_the_outside() {
+        let ray = Ray::new(point(0.0, 0.0, -5.0), vector(0.0, 0.0, 1.0));
+        let sphere = Sphere::new();
+        let comps = Intersection::new(Float::new(4.0), &sphere).prepare_computations(&ray);
+
+        assert!(!comps.inside());
+    }
+
+    #[test]
+    fn hit_when_an_intersection_occurs_on_the_inside() {
+        let ray = Ray::new(point(0.0, 0.0, 0.0), vector(0.0, 0.0, 1.0));
+        let sphere = Sphere::new();
+        let comps = Intersection::new(Float::new(1.0), &sphere).prepare_computations(&ray);
+
+        assert_eq!(comps.point(), point(0.0, 0.0, 1.0));
+        assert_eq!(comps.eyev(), vector(0.0, 0.0, -1.0));
+        assert!(comps.inside());
+        // normal would have been (0, 0, 1), but is inverted
+        assert_eq!(comps.normalv(), vector(0.0, 0.0, -1.0));
+    }
+
+    #[test]
+    fn hit_offsets_the_over_point() {
+        let ray = Ray::new(point(0.0, 0.0, -5.0), vector(0.0, 0.0, 1.0));
+        let mut sphere = Sphere::new();
+        sphere.set_transform(Matrix4::translation(
+            Float::new(0.0),
+            Float::new(0.0),
+            Float::new(1.0),
+        ));
+        let comps = Intersection::new(Float::new(5.0), &sphere).prepare_computations(&ray);
+
+        // Float comparisons are tolerant to EPSILON, so compare raw values
+        assert!(comps.over_point().z().value() < -Float::EPSILON.value() / 2.0);
+        assert!(comps.point().z().value() > comps.over_point().z().value());
+    }
+
+    #[test]
+    fn precomputing_the_reflection_vector() {
+        let plane = Plane::new();
+        let ray = Ray::new(
+            point(0.0, 1.0, -1.0),
+            vector(0.0, -FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+        );
+        let comps = Intersection::new(Float::new(SQRT_2), &plane).prepare_computations(&ray);
+
+        assert_eq!(comps.reflectv(), vector(0.0, FRAC_1_SQRT_2, FRAC_1_SQRT_2));
+    }
+
+    #[test]
+    fn finding_n1_and_n2_at_various_intersections() {
+        let mut a = glass_sphere();
+        a.set_transform(Matrix4::scaling(
+            Float::new(2.0),
+            Float::new(2.0),
+            Float::new(2.0),
+        ));
+        let mut b = glass_sphere();
+        b.set_transform(Matrix4::translation(
+            Float::new(0.0),
+            Float::new(0.0),
+            Float::new(-0.25),
+        ));
+        let mut c = glass_sphere();
+        c.set_transform(Matrix4::translation(
+            Float::new(0.0),
+            Float::new(0.0),
+            Float::new(0.25),
+        ));
+        for (sphere, index) in [(&mut a, 1.5), (&mut b, 2.0), (&mut c, 2.5)] {
+            let mut material = sphere.material().clone();
+            material.set_refractive_index(Float::new(index));
+            sphere.set_material(material);
+        }
+        let ray = Ray::new(point(0.0, 0.0, -4.0), vector(0.0, 0.0, 1.0));
+        let xs = Intersections::new(vec![
+            Intersection::new(Float::new(2.0), &a),
+            Intersection::new(Float::new(2.75), &b),
+            Intersection::new(Float::new(3.25), &c),
+            Intersection::new(Float::new(4.75), &b),
+            Intersection::new(Float::new(5.25), &c),
+            Intersection::new(Float::new(6.0), &a),
+        ]);
+        let expected = [
+            (1.0, 1.5),
+            (1.5, 2.0),
+            (2.0, 2.5),
+            (2.5, 2.5),
+            (2.5, 1.5),
+            (1.5, 1.0),
+        ];
+
+        for (index, (n1, n2)) in expected.into_iter().enumerate() {
+            let comps = xs[index].prepare_computations_with(&ray, &xs);
+            assert_eq!(comps.n1(), Float::new(n1));
+            assert_eq!(comps.n2(), Float::new(n2));
+        }
+    }
+
+    #[test]
+    fn a_lone_hit_is_entered_from_empty_space() {
+        let ray = Ray::new(point(0.0, 0.0, -5.0), vector(0.0, 0.0, 1.0));
+        let sphere = glass_sphere();
+        let comps = Intersection::new(Float::new(4.0), &sphere).prepare_computations(&ray);
+
+        assert_eq!(comps.n1(), Float::new(1.0));
+        assert_eq!(comps.n2(), Float::new(1.5));
+    }
+
+    #[test]
+    fn the_under_point_is_offset_below_the_surface() {
+        let ray = Ray::new(point(0.0, 0.0, -5.0), vector(0.0, 0.0, 1.0));
+        let mut sphere = glass_sphere();
+        sphere.set_transform(Matrix4::translation(
+            Float::new(0.0),
+            Float::new(0.0),
+            Float::new(1.0),
+        ));
+        let hit = Intersection::new(Float::new(5.0), &sphere);
+        let comps = hit.prepare_computations_with(&ray, &Intersections::new(vec![hit]));
+
+        // Float comparisons are tolerant to EPSILON, so compare raw values
+        assert!(comps.under_point().z().value() > Float::EPSILON.value() / 2.0);
+        assert!(comps.point().z().value() < comps.under_point().z().value());
+    }
+
+    #[test]
+    fn schlick_approximation_under_total_internal_reflection() {
+        let sphere = glass_sphere();
+        let ray = Ray::new(point(0.0, 0.0, FRAC_1_SQRT_2), vector(0.0, 1.0, 0.0));
+        let xs = Intersections::new(vec![
+            Intersection::new(Float::new(-FRAC_1_SQRT_2), &sphere),
+            Intersection::new(Float::new(FRAC_1_SQRT_2), &sphere),
+        ]);
+        let comps = xs[1].prepare_computations_with(&ray, &xs);
+
+        assert_eq!(comps.schlick(), Float::new(1.0));
+    }
+
+    #[test]
+    fn schlick_approximation_with_a_perpendicular_viewing_angle() {
+        let sphere = glass_sphere();
+        let ray = Ray::new(point(0.0, 0.0, 0.0), vector(0.0, 1.0, 0.0));
+        let xs = Intersections::new(vec![
+            Intersection::new(Float::new(-1.0), &sphere),
+            Intersection::new(Float::new(1.0), &sphere),
+        ]);
+        let comps = xs[1].prepare_computations_with(&ray, &xs);
+
+        assert_eq!(comps.schlick(), Float::new(0.04));
+    }
+
+    #[test]
+    fn schlick_approximation_with_small_angle_and_n2_greater_than_n1() {
+        let sphere = glass_sphere();
+        let ray = Ray::new(point(0.0, 0.99, -2.0), vector(0.0, 0.0, 1.0));
+        let xs = Intersections::new(vec![Intersection::new(Float::new(1.8589), &sphere)]);
+        let comps = xs[0].prepare_computations_with(&ray, &xs);
+
+        assert_eq!(comps.schlick(), Float::new(0.48873));
+    }
+}
@@ -9,4 +9,11 @@ mod tests {
 
 pub mod elementary;
 pub mod engine;
-mod ray;
+pub mod intersection;
+pub mod noise;
+pub mod obj;
+pub mod ray;
+pub mod shape;
+
+#[cfg(test)]
+mod test_helpers;
@@ -0,0 +1,414 @@
+use crate::elementary::float::Float;
+use crate::elementary::point::Point;
+
+use std::fmt;
+
+/// A deterministic source of smooth pseudo-random values over space. The
+/// same generator and point always give the same value, on every run and
+/// every platform.
+pub trait Noise: fmt::Debug {
+    /// Noise at `point`, roughly within -1 to 1.
+    fn noise(&self, point: Point) -> Float;
+}
+
+/// Ken Perlin's improved gradient noise, which is exactly 0 on every
+/// integer lattice point.
+#[derive(Debug, Clone)]
+pub struct Perlin {
+    permutation: [u8; 512],
+}
+
+impl Perlin {
+    pub fn new() -> Perlin {
+        Perlin::with_seed(DEFAULT_SEED)
+    }
+
+    /// A generator whose lattice is shuffled by `seed`; different seeds give
+    /// unrelated noise.
+    pub fn with_seed(seed: u64) -> Perlin {
+        Perlin {
+            permutation: permutation(seed),
+        }
+    }
+}
+
+impl Default for Perlin {
+    fn default() -> Self {
+        Perlin::new()
+    }
+}
+
+impl Noise for Perlin {
+    fn noise(&self, point: Point) -> Float {
+        let (x, y, z) = (point.x().value(), point.y().value(), point.z().value());
+        let (xi, yi, zi) = (lattice(x), lattice(y), lattice(z));
+        let (x, y, z) = (x - x.floor(), y - y.floor(), z - z.floor());
+        let (u, v, w) = (fade(x), fade(y), fade(z));
+
+        let p = &self.permutation;
+        let a = p[xi] as usize + yi;
+        let aa = p[a] as usize + zi;
+        let ab = p[a + 1] as usize + zi;
+        let b = p[xi + 1] as usize + yi;
+        let ba = p[b] as usize + zi;
+        let bb = p[b + 1] as usize + zi;
+
+        let value = lerp(
+            w,
+            lerp(
+                v,
+                lerp(u, grad(p[aa], x, y, z), grad(p[ba], x - 1.0, y, z)),
+                lerp(
+                    u,
+                    grad(p[ab], x, y - 1.0, z),
+                    grad(p[bb], x - 1.0, y - 1.0, z),
+                ),
+            ),
+            lerp(
+                v,
+                lerp(
+                    u,
+                    grad(p[aa + 1], x, y, z - 1.0),
+                    grad(p[ba + 1], x - 1.0, y, z - 1.0),
+                ),
+                lerp(
+                    u,
+                    grad(p[ab + 1], x, y - 1.0, z - 1.0),
+                    grad(p[bb + 1], x - 1.0, y - 1.0, z - 1.0),
+                ),
+            ),
+        );
+
+        Float::new(value)
+    }
+}
+
+/// Simplex noise as described by Stefan Gustavson: it sums the influence of
+/// the four corners of the tetrahedron a point falls in, which is cheaper
+/// than Perlin noise's eight and has fewer axis-aligned artifacts.
+#[derive(Debug, Clone)]
+pub struct Simplex {
+    permutation: [u8; 512],
+}
+
+impl Simplex {
+    pub fn new() -> Simplex {
+        Simplex::with_seed(DEFAULT_SEED)
+    }
+
+    pub fn with_seed(seed: u64) -> Simplex {
+        Simplex {
+            permutation: permutation(seed),
+        }
+    }
+}
+
+impl Default for Simplex {
+    fn default() -> Self {
+        Simplex::new()
+    }
+}
+
+impl Noise for Simplex {
+    fn noise(&self, point: Point) -> Float {
+        const F3: f64 = 1.0 / 3.0;
+        const G3: f64 = 1.0 / 6.0;
+
+        let (x, y, z) = (point.x().value(), point.y().value(), point.z().value());
+
+        // skew into the grid of tetrahedra to find the containing cell
+        let skew = (x + y + z) * F3;
+        let (i, j, k) = ((x + skew).floor(), (y + skew).floor(), (z + skew).floor());
+        let unskew = (i + j + k) * G3;
+        let first = [x - (i - unskew), y - (j - unskew), z - (k - unskew)];
+
+        // the order of the offsets picks which of six tetrahedra holds the point
+        let [x0, y0, z0] = first;
+        let (second, third) = if x0 >= y0 {
+            if y0 >= z0 {
+                ([1, 0, 0], [1, 1, 0])
+            } else if x0 >= z0 {
+                ([1, 0, 0], [1, 0, 1])
+            } else {
+                ([0, 0, 1], [1, 0, 1])
+            }
+        } else if y0 < z0 {
+            ([0, 0, 1], [0, 1, 1])
+        } else if x0 < z0 {
+            ([0, 1, 0], [0, 1, 1])
+        } else {
+            ([0, 1, 0], [1, 1, 0])
+        };
+
+        let p = &self.permutation;
+        let (ii, jj, kk) = (lattice(i), lattice(j), lattice(k));
+        let corners = [[0, 0, 0], second, third, [1, 1, 1]];
+
+        let value: f64 = corners
+            .iter()
+            .enumerate()
+            .map(|(n, corner)| {
+                let offset = [
+                    first[0] - corner[0] as f64 + n as f64 * G3,
+                    first[1] - corner[1] as f64 + n as f64 * G3,
+                    first[2] - corner[2] as f64 + n as f64 * G3,
+                ];
+                let falloff =
+                    0.6 - offset[0] * offset[0] - offset[1] * offset[1] - offset[2] * offset[2];
+                if falloff < 0.0 {
+                    return 0.0;
+                }
+
+                let hash =
+                    p[ii + corner[0] + p[jj + corner[1] + p[kk + corner[2]] as usize] as usize];
+                falloff.powi(4) * grad(hash % 12, offset[0], offset[1], offset[2])
+            })
+            .sum();
+
+        // scales the sum to roughly -1 to 1
+        Float::new(32.0 * value)
+    }
+}
+
+/// Fractal Brownian motion: `octaves` layers of noise, each at twice the
+/// frequency and half the amplitude of the last, normalized to stay roughly
+/// within -1 to 1. Adds fine detail on top of the broad shape.
+pub fn fbm(noise: &dyn Noise, point: Point, octaves: usize) -> Float {
+    fractal(noise, point, octaves, |value| value)
+}
+
+/// Like `fbm`, but sums the absolute value of each layer, giving values
+/// from 0 to about 1 with sharp creases where the noise crosses zero.
+pub fn turbulence(noise: &dyn Noise, point: Point, octaves: usize) -> Float {
+    fractal(noise, point, octaves, |value| value.abs())
+}
+
+fn fractal(noise: &dyn Noise, point: Point, octaves: usize, layer: fn(f64) -> f64) -> Float {
+    let (mut sum, mut total_amplitude) = (0.0, 0.0);
+    let (mut frequency, mut amplitude) = (1.0, 1.0);
+
+    for _ in 0..octaves {
+        let scaled = Point::new(
+            point.x() * Float::new(frequency),
+            point.y() * Float::new(frequency),
+            point.z() * Float::new(frequency),
+        );
+        sum += layer(noise.noise(scaled).value()) * amplitude;
+        total_amplitude += amplitude;
+        frequency *= 2.0;
+        amplitude *= 0.5;
+    }
+
+    if total_amplitude == 0.0 {
+        return Float::new(0.0);
+    }
+
+    Float::new(sum / total_amplitude)
+}
+
+const DEFAULT_SEED: u64 = 0x2545_f491_4f6c_dd1d;
+
+/// The numbers 0 to 255 shuffled by `seed`, repeated twice so lookups can
+/// add a lattice offset without wrapping.
+fn permutation(seed: u64) -> [u8; 512] {
+    let mut table = [0u8; 256];
+    for (i, entry) in table.iter_mut().enumerate() {
+        *entry = i as u8;
+    }
+
+    // Fisher-Yates shuffle driven by xorshift64, seeded through splitmix64
+    // so that nearby seeds diverge at once; xorshift must not start at 0
+    let mut state = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    state = (state ^ (state >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    state = (state ^ (state >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    state ^= state >> 31;
+    if state == 0 {
+        state = DEFAULT_SEED;
+    }
+    for i in (1..table.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        table.swap(i, (state % (i as u64 + 1)) as usize);
+    }
+
+    let mut permutation = [0u8; 512];
+    for (i, entry) in permutation.iter_mut().enumerate() {
+        *entry = table[i % 256];
+    }
+
+    permutation
+}
+
+/// The lattice cell a coordinate falls in, wrapped to the permutation size.
+fn lattice(value: f64) -> usize {
+    (value.floor() as i64).rem_euclid(256) as usize
+}
+
+/// Smooths the position within a cell so the noise has no visible seams.
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(t: f64, a: f64, b: f64) -> f64 {
+    a + t * (b - a)
+}
+
+/// Dot product of the offset with one of twelve edge gradients picked by
+/// the hash; the four extra hash values repeat edges, as in Perlin's
+/// reference implementation.
+fn grad(hash: u8, x: f64, y: f64, z: f64) -> f64 {
+    let h = hash & 15;
+    let u = if h < 8 { x } else { y };
+    let v = if h < 4 {
+        y
+    } else if h == 12 || h == 14 {
+        x
+    } else {
+        z
+    };
+
+    (if h & 1 == 0 { u } else { -u }) + (if h & 2 == 0 { v } else { -v })
+}
+
+#[cfg(test)]
+mod noise_tests {
+    use super::fbm;
+    use super::turbulence;
+    use super::Float;
+    use super::Noise;
+    use super::Perlin;
+    use super::Point;
+    use super::Simplex;
+    use crate::test_helpers::point;
+
+    /// Points spread over several lattice cells, including negative ones.
+    fn samples() -> impl Iterator<Item = Point> {
+        (0..1000).map(|i| {
+            let t = i as f64 * 0.173;
+            point(t.sin() * 7.3, t * 0.31 - 20.0, (t * 0.7).cos() * 3.1)
+        })
+    }
+
+    #[test]
+    fn perlin_noise_is_deterministic() {
+        let first = Perlin::new();
+        let second = Perlin::new();
+
+        for p in samples() {
+            assert_eq!(first.noise(p).value(), second.noise(p).value());
+        }
+    }
+
+    #[test]
+    fn perlin_noise_vanishes_on_the_lattice() {
+        let perlin = Perlin::new();
+
+        for (x, y, z) in [(0.0, 0.0, 0.0), (3.0, -2.0, 7.0), (-100.0, 255.0, 256.0)] {
+            assert_eq!(perlin.noise(point(x, y, z)), Float::new(0.0));
+        }
+    }
+
+    #[test]
+    fn perlin_noise_stays_in_range_and_varies() {
+        let perlin = Perlin::new();
+        let values = samples()
+            .map(|p| perlin.noise(p).value())
+            .collect::<Vec<f64>>();
+
+        assert!(values.iter().all(|value| (-1.0..=1.0).contains(value)));
+        assert!(values.iter().any(|value| *value > 0.2));
+        assert!(values.iter().any(|value| *value < -0.2));
+    }
+
+    #[test]
+    fn perlin_noise_is_continuous() {
+        let perlin = Perlin::new();
+
+        for p in samples() {
+            let nearby = point(p.x().value() + 1e-4, p.y().value(), p.z().value());
+            let change = (perlin.noise(p) - perlin.noise(nearby)).abs().value();
+            assert!(change < 1e-3);
+        }
+    }
+
+    #[test]
+    fn seeds_give_unrelated_noise() {
+        let first = Perlin::with_seed(1);
+        let second = Perlin::with_seed(2);
+        let differing = samples()
+            .filter(|p| (first.noise(*p) - second.noise(*p)).abs().value() > 0.01)
+            .count();
+
+        assert!(differing > 500);
+        assert_eq!(
+            Perlin::with_seed(1).noise(point(0.5, 1.5, 2.5)).value(),
+            first.noise(point(0.5, 1.5, 2.5)).value()
+        );
+    }
+
+    #[test]
+    fn simplex_noise_is_deterministic_and_seedable() {
+        let first = Simplex::new();
+        let second = Simplex::new();
+        let other = Simplex::with_seed(7);
+
+        assert!(samples().all(|p| first.noise(p).value() == second.noise(p).value()));
+        assert!(samples().any(|p| first.noise(p) != other.noise(p)));
+    }
+
+    #[test]
+    fn simplex_noise_stays_in_range_and_is_continuous() {
+        let simplex = Simplex::new();
+
+        for p in samples() {
+            let value = simplex.noise(p).value();
+            let nearby = point(p.x().value(), p.y().value() + 1e-4, p.z().value());
+
+            assert!((-1.0..=1.0).contains(&value));
+            assert!((simplex.noise(p) - simplex.noise(nearby)).abs().value() < 1e-2);
+        }
+        assert!(samples().any(|p| simplex.noise(p).value() > 0.2));
+        assert!(samples().any(|p| simplex.noise(p).value() < -0.2));
+    }
+
+    #[test]
+    fn a_single_octave_of_fbm_is_the_noise_itself() {
+        let perlin = Perlin::new();
+
+        for p in samples() {
+            assert_eq!(fbm(&perlin, p, 1).value(), perlin.noise(p).value());
+        }
+        assert_eq!(fbm(&perlin, point(0.3, 0.6, 0.9), 0), Float::new(0.0));
+    }
+
+    #[test]
+    fn fbm_and_turbulence_stay_in_range() {
+        let simplex = Simplex::new();
+
+        for p in samples() {
+            let fbm = fbm(&simplex, p, 6).value();
+            let turbulence = turbulence(&simplex, p, 6).value();
+
+            assert!((-1.0..=1.0).contains(&fbm));
+            assert!((0.0..=1.0).contains(&turbulence));
+        }
+    }
+
+    #[test]
+    fn more_octaves_add_finer_detail() {
+        let perlin = Perlin::new();
+        let roughness = |octaves: usize| {
+            samples()
+                .map(|p| {
+                    let nearby = point(p.x().value() + 0.05, p.y().value(), p.z().value());
+                    (fbm(&perlin, p, octaves) - fbm(&perlin, nearby, octaves))
+                        .abs()
+                        .value()
+                })
+                .sum::<f64>()
+        };
+
+        assert!(roughness(6) > roughness(1));
+    }
+}
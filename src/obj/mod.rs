@@ -0,0 +1,734 @@
+use crate::elementary::float::Float;
+use crate::elementary::point::Point;
+use crate::elementary::vector::Vector;
+use crate::engine::material::Material;
+use crate::shape::group::Group;
+use crate::shape::smooth_triangle::SmoothTriangle;
+use crate::shape::triangle::Triangle;
+use crate::shape::Shape;
+
+use std::path::Path;
+
+pub mod mtl;
+
+use mtl::{MtlLibrary, MtlMaterial};
+
+/// A named set of faces, started by a `g` or `o` statement.
+#[derive(Debug)]
+pub struct ObjGroup {
+    name: String,
+    shapes: Vec<Box<dyn Shape>>,
+}
+
+impl ObjGroup {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn shapes(&self) -> &[Box<dyn Shape>] {
+        &self.shapes
+    }
+}
+
+/// The contents of a Wavefront OBJ file. Faces are triangulated as they are
+/// read, becoming `SmoothTriangle`s when every corner names a vertex normal
+/// and plain `Triangle`s otherwise, and keep the `vt` coordinates of their
+/// corners when every corner names one. Each face takes the material named
+/// by the latest `usemtl`, looked up in the libraries loaded through
+/// `mtllib`.
+#[derive(Debug, Default)]
+pub struct ObjParser {
+    vertices: Vec<Point>,
+    normals: Vec<Vector>,
+    texture_coords: Vec<(Float, Float)>,
+    materials: Vec<MtlMaterial>,
+    default_group: Vec<Box<dyn Shape>>,
+    groups: Vec<ObjGroup>,
+    ignored: Vec<(usize, String)>,
+}
+
+/// One corner of a face as indices into the vertex, texture and normal lists.
+#[derive(Debug, Copy, Clone)]
+struct FaceVertex {
+    vertex: usize,
+    texture: Option<usize>,
+    normal: Option<usize>,
+}
+
+impl ObjParser {
+    /// Parses an OBJ file held in memory. There is nowhere to find `mtllib`
+    /// files from, so use `parse_with_loader` when materials matter.
+    pub fn parse(input: &str) -> Result<ObjParser, String> {
+        ObjParser::parse_with_loader(input, |_| Ok(MtlLibrary::default()))
+    }
+
+    /// Parses an OBJ file, handing each library named by `mtllib` to `load`.
+    pub fn parse_with_loader<F>(input: &str, mut load: F) -> Result<ObjParser, String>
+    where
+        F: FnMut(&str) -> Result<MtlLibrary, String>,
+    {
+        let mut parser = ObjParser::default();
+        let mut current_group: Option<usize> = None;
+        let mut current_material: Option<Material> = None;
+
+        for (index, line) in input.lines().enumerate() {
+            let line_number = index + 1;
+            let mut tokens = line.split_whitespace();
+
+            match tokens.next() {
+                Some("v") => {
+                    let [x, y, z] = parse_floats(tokens, line_number)?;
+                    parser.vertices.push(Point::new(x, y, z));
+                }
+                Some("vn") => {
+                    let [x, y, z] = parse_floats(tokens, line_number)?;
+                    parser.normals.push(Vector::new(x, y, z));
+                }
+                Some("vt") => {
+                    // v is optional and defaults to 0
+                    let [u] = parse_floats(tokens.by_ref().take(1), line_number)?;
+                    let v = match tokens.next() {
+                        Some(token) => parse_float(token, line_number)?,
+                        None => Float::new(0.0),
+                    };
+                    parser.texture_coords.push((u, v));
+                }
+                Some("f") => {
+                    let corners = tokens
+                        .map(|token| parser.parse_face_vertex(token, line_number))
+                        .collect::<Result<Vec<FaceVertex>, String>>()?;
+                    if corners.len() < 3 {
+                        return Err(format!("line {}: a face needs three vertices", line_number));
+                    }
+
+                    let mut triangles = parser.fan_triangulation(&corners);
+                    if let Some(material) = &current_material {
+                        for triangle in triangles.iter_mut() {
+                            triangle.set_material(material.clone());
+                        }
+                    }
+
+                    match current_group {
+                        Some(group) => parser.groups[group].shapes.extend(triangles),
+                        None => parser.default_group.extend(triangles),
+                    }
+                }
+                Some("g") | Some("o") => {
+                    let name = tokens.collect::<Vec<&str>>().join(" ");
+                    current_group = Some(parser.group_index(name));
+                }
+                Some("mtllib") => {
+                    // a library that fails to load leaves its faces with the
+                    // default material rather than losing the whole mesh
+                    for file in tokens {
+                        match load(file) {
+                            Ok(library) => {
+                                parser.materials.extend(library.materials().iter().cloned())
+                            }
+                            Err(error) => parser
+                                .ignored
+                                .push((line_number, format!("{}: {}", line, error))),
+                        }
+                    }
+                }
+                Some("usemtl") => {
+                    let name = tokens.collect::<Vec<&str>>().join(" ");
+                    match parser.material(&name) {
+                        Some(material) => current_material = Some(material.to_material()),
+                        // an unknown material leaves faces with the default
+                        None => {
+                            current_material = None;
+                            parser.ignored.push((line_number, line.to_string()));
+                        }
+                    }
+                }
+                _ => parser.ignored.push((line_number, line.to_string())),
+            }
+        }
+
+        Ok(parser)
+    }
+
+    /// Reads an OBJ file, loading `mtllib` files relative to its directory.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<ObjParser, String> {
+        let path = path.as_ref();
+        let input = std::fs::read_to_string(path)
+            .map_err(|error| format!("{}: {}", path.display(), error))?;
+        let directory = path.parent().unwrap_or_else(|| Path::new(""));
+
+        ObjParser::parse_with_loader(&input, |file| MtlLibrary::from_file(directory.join(file)))
+            .map_err(|error| format!("{}: {}", path.display(), error))
+    }
+
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    pub fn normals(&self) -> &[Vector] {
+        &self.normals
+    }
+
+    pub fn texture_coords(&self) -> &[(Float, Float)] {
+        &self.texture_coords
+    }
+
+    /// Every material declared by the loaded `mtllib` files.
+    pub fn materials(&self) -> &[MtlMaterial] {
+        &self.materials
+    }
+
+    pub fn material(&self, name: &str) -> Option<&MtlMaterial> {
+        self.materials
+            .iter()
+            .find(|material| material.name() == name)
+    }
+
+    /// Faces that appear before any `g` or `o` statement.
+    pub fn default_group(&self) -> &[Box<dyn Shape>] {
+        &self.default_group
+    }
+
+    pub fn groups(&self) -> &[ObjGroup] {
+        &self.groups
+    }
+
+    pub fn group(&self, name: &str) -> Option<&ObjGroup> {
+        self.groups.iter().find(|group| group.name == name)
+    }
+
+    /// Lines that were not understood, with their 1-based line numbers. A
+    /// `mtllib` library that failed to load is listed with the reason.
+    pub fn ignored(&self) -> &[(usize, String)] {
+        &self.ignored
+    }
+
+    /// Gathers every face into one group that can be placed in a scene.
+    /// Faces from the default group become direct children, and each named
+    /// group becomes a child group of its own.
+    pub fn into_group(self) -> Group {
+        let mut group = Group::new();
+        for shape in self.default_group {
+            group.add_boxed_child(shape);
+        }
+        for obj_group in self.groups {
+            let mut child = Group::new();
+            for shape in obj_group.shapes {
+                child.add_boxed_child(shape);
+            }
+            group.add_child(child);
+        }
+
+        group
+    }
+
+    fn group_index(&mut self, name: String) -> usize {
+        match self.groups.iter().position(|group| group.name == name) {
+            Some(index) => index,
+            None => {
+                self.groups.push(ObjGroup {
+                    name,
+                    shapes: vec![],
+                });
+                self.groups.len() - 1
+            }
+        }
+    }
+
+    fn parse_face_vertex(&self, token: &str, line_number: usize) -> Result<FaceVertex, String> {
+        // corners come as `v`, `v/vt`, `v//vn` or `v/vt/vn`
+        let mut parts = token.split('/');
+        let vertex = resolve_index(parts.next(), self.vertices.len(), line_number)?;
+        let texture = match parts.next().filter(|part| !part.is_empty()) {
+            Some(texture) => Some(resolve_index(
+                Some(texture),
+                self.texture_coords.len(),
+                line_number,
+            )?),
+            None => None,
+        };
+        let normal = match parts.next().filter(|part| !part.is_empty()) {
+            Some(normal) => Some(resolve_index(
+                Some(normal),
+                self.normals.len(),
+                line_number,
+            )?),
+            None => None,
+        };
+
+        Ok(FaceVertex {
+            vertex,
+            texture,
+            normal,
+        })
+    }
+
+    fn fan_triangulation(&self, corners: &[FaceVertex]) -> Vec<Box<dyn Shape>> {
+        let smooth = corners.iter().all(|corner| corner.normal.is_some());
+
+        (1..corners.len() - 1)
+            .map(|i| {
+                let (a, b, c) = (corners[0], corners[i], corners[i + 1]);
+                let (p1, p2, p3) = (
+                    self.vertices[a.vertex],
+                    self.vertices[b.vertex],
+                    self.vertices[c.vertex],
+                );
+
+                let texture_coords = match (a.texture, b.texture, c.texture) {
+                    (Some(t1), Some(t2), Some(t3)) => Some([
+                        self.texture_coords[t1],
+                        self.texture_coords[t2],
+                        self.texture_coords[t3],
+                    ]),
+                    _ => None,
+                };
+
+                match (a.normal, b.normal, c.normal) {
+                    (Some(n1), Some(n2), Some(n3)) if smooth => {
+                        let mut triangle = SmoothTriangle::new(
+                            p1,
+                            p2,
+                            p3,
+                            self.normals[n1],
+                            self.normals[n2],
+                            self.normals[n3],
+                        );
+                        if let Some(texture_coords) = texture_coords {
+                            triangle.set_texture_coords(texture_coords);
+                        }
+                        Box::new(triangle) as Box<dyn Shape>
+                    }
+                    _ => {
+                        let mut triangle = Triangle::new(p1, p2, p3);
+                        if let Some(texture_coords) = texture_coords {
+                            triangle.set_texture_coords(texture_coords);
+                        }
+                        Box::new(triangle) as Box<dyn Shape>
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+fn parse_floats<'a, const N: usize>(
+    tokens: impl Iterator<Item = &'a str>,
+    line_number: usize,
+) -> Result<[Float; N], String> {
+    let mut values = [Float::new(0.0); N];
+    let mut count = 0;
+
+    // extra components such as the optional w of a vertex are ignored
+    for (value, token) in values.iter_mut().zip(tokens) {
+        *value = parse_float(token, line_number)?;
+        count += 1;
+    }
+
+    if count < N {
+        return Err(format!("line {}: expected {} numbers", line_number, N));
+    }
+
+    Ok(values)
+}
+
+fn parse_float(token: &str, line_number: usize) -> Result<Float, String> {
+    token
+        .parse::<f64>()
+        .map(Float::new)
+        .map_err(|_| format!("line {}: invalid number {:?}", line_number, token))
+}
+
+/// Converts a 1-based OBJ index, or a negative index counting back from the
+/// most recent element, into a position in a list of `len` elements.
+fn resolve_index(token: Option<&str>, len: usize, line_number: usize) -> Result<usize, String> {
+    let token = token.unwrap_or("");
+    let index = token
+        .parse::<i64>()
+        .map_err(|_| format!("line {}: invalid index {:?}", line_number, token))?;
+
+    let resolved = if index > 0 {
+        index - 1
+    } else {
+        len as i64 + index
+    };
+
+    if index == 0 || resolved < 0 || resolved >= len as i64 {
+        return Err(format!(
+            "line {}: index {} is out of range",
+            line_number, index
+        ));
+    }
+
+    Ok(resolved as usize)
+}
+
+#[cfg(test)]
+mod obj_tests {
+    use super::Float;
+    use super::Group;
+    use super::Material;
+    use super::MtlLibrary;
+    use super::ObjParser;
+    use super::Shape;
+    use super::SmoothTriangle;
+    use super::Triangle;
+    use crate::engine::color::Color;
+    use crate::test_helpers::{downcast, point, vector};
+
+    fn triangle(shape: &dyn Shape) -> &Triangle {
+        downcast::<Triangle>(shape).unwrap()
+    }
+
+    fn smooth_triangle(shape: &dyn Shape) -> &SmoothTriangle {
+        downcast::<SmoothTriangle>(shape).unwrap()
+    }
+
+    #[test]
+    fn ignores_unrecognized_lines() {
+        let gibberish = "There was a young lady named Bright\n\
+                         who traveled much faster than light.\n\
+                         She set out one day\n\
+                         in a relative way,\n\
+                         and came back the previous night.";
+        let parser = ObjParser::parse(gibberish).unwrap();
+
+        assert_eq!(parser.ignored().len(), 5);
+        assert_eq!(
+            parser.ignored()[2],
+            (3, String::from("She set out one day"))
+        );
+    }
+
+    #[test]
+    fn parses_vertex_records() {
+        let parser =
+            ObjParser::parse("v -1 1 0\nv -1.0000 0.5000 0.0000\nv 1 0 0\nv 1 1 0").unwrap();
+
+        assert_eq!(parser.vertices().len(), 4);
+        assert_eq!(parser.vertices()[0], point(-1.0, 1.0, 0.0));
+        assert_eq!(parser.vertices()[1], point(-1.0, 0.5, 0.0));
+        assert_eq!(parser.vertices()[2], point(1.0, 0.0, 0.0));
+        assert_eq!(parser.vertices()[3], point(1.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn parses_triangle_faces() {
+        let parser =
+            ObjParser::parse("v -1 1 0\nv -1 0 0\nv 1 0 0\nv 1 1 0\n\nf 1 2 3\nf 1 3 4").unwrap();
+        let group = parser.default_group();
+        let t1 = triangle(group[0].as_ref());
+        let t2 = triangle(group[1].as_ref());
+
+        assert_eq!(group.len(), 2);
+        assert_eq!(t1.p1(), parser.vertices()[0]);
+        assert_eq!(t1.p2(), parser.vertices()[1]);
+        assert_eq!(t1.p3(), parser.vertices()[2]);
+        assert_eq!(t2.p1(), parser.vertices()[0]);
+        assert_eq!(t2.p2(), parser.vertices()[2]);
+        assert_eq!(t2.p3(), parser.vertices()[3]);
+    }
+
+    #[test]
+    fn triangulates_polygons() {
+        let parser =
+            ObjParser::parse("v -1 1 0\nv -1 0 0\nv 1 0 0\nv 1 1 0\nv 0 2 0\n\nf 1 2 3 4 5")
+                .unwrap();
+        let group = parser.default_group();
+
+        assert_eq!(group.len(), 3);
+        for (shape, (b, c)) in group.iter().zip([(1, 2), (2, 3), (3, 4)]) {
+            let t = triangle(shape.as_ref());
+            assert_eq!(t.p1(), parser.vertices()[0]);
+            assert_eq!(t.p2(), parser.vertices()[b]);
+            assert_eq!(t.p3(), parser.vertices()[c]);
+        }
+    }
+
+    #[test]
+    fn puts_triangles_in_named_groups() {
+        let input = "v -1 1 0\nv -1 0 0\nv 1 0 0\nv 1 1 0\n\
+                     g FirstGroup\nf 1 2 3\n\
+                     o SecondGroup\nf 1 3 4\n\
+                     g FirstGroup\nf 4 3 2";
+        let parser = ObjParser::parse(input).unwrap();
+        let first = parser.group("FirstGroup").unwrap();
+        let second = parser.group("SecondGroup").unwrap();
+
+        assert!(parser.default_group().is_empty());
+        assert_eq!(parser.groups().len(), 2);
+        assert_eq!(first.shapes().len(), 2);
+        assert_eq!(second.shapes().len(), 1);
+        assert_eq!(
+            triangle(first.shapes()[0].as_ref()).p2(),
+            parser.vertices()[1]
+        );
+        assert_eq!(
+            triangle(second.shapes()[0].as_ref()).p3(),
+            parser.vertices()[3]
+        );
+        assert_eq!(
+            triangle(first.shapes()[1].as_ref()).p1(),
+            parser.vertices()[3]
+        );
+    }
+
+    #[test]
+    fn converts_to_a_group() {
+        let input = "v -1 1 0\nv -1 0 0\nv 1 0 0\nv 1 1 0\n\
+                     f 1 2 3\n\
+                     g FirstGroup\nf 1 2 3\n\
+                     g SecondGroup\nf 1 3 4";
+        let group = ObjParser::parse(input).unwrap().into_group();
+        let children = group.children();
+
+        assert_eq!(children.len(), 3);
+        assert!(downcast::<Triangle>(children[0].as_ref()).is_some());
+        for child in &children[1..] {
+            let child = downcast::<Group>(child.as_ref()).unwrap();
+            assert_eq!(child.children().len(), 1);
+            assert_eq!(child.children()[0].parent_inverses().len(), 2);
+        }
+    }
+
+    #[test]
+    fn parses_vertex_normal_and_texture_records() {
+        let parser =
+            ObjParser::parse("vn 0 0 1\nvn 0.707 0 -0.707\nvn 1 2 3\nvt 0.5 0.25").unwrap();
+
+        assert_eq!(parser.normals().len(), 3);
+        assert_eq!(parser.normals()[0], vector(0.0, 0.0, 1.0));
+        assert_eq!(parser.normals()[1], vector(0.707, 0.0, -0.707));
+        assert_eq!(parser.normals()[2], vector(1.0, 2.0, 3.0));
+        assert_eq!(
+            parser.texture_coords(),
+            &[(Float::new(0.5), Float::new(0.25))]
+        );
+    }
+
+    #[test]
+    fn parses_faces_with_normals() {
+        let input = "v 0 1 0\nv -1 0 0\nv 1 0 0\n\
+                     vn -1 0 0\nvn 1 0 0\nvn 0 1 0\nvt 0 0\n\
+                     f 1//3 2//1 3//2\n\
+                     f 1/1/3 2/1/1 3/1/2";
+        let parser = ObjParser::parse(input).unwrap();
+        let group = parser.default_group();
+
+        assert_eq!(group.len(), 2);
+        for shape in group {
+            let t = smooth_triangle(shape.as_ref());
+            assert_eq!(t.p1(), parser.vertices()[0]);
+            assert_eq!(t.p2(), parser.vertices()[1]);
+            assert_eq!(t.p3(), parser.vertices()[2]);
+            assert_eq!(t.n1(), parser.normals()[2]);
+            assert_eq!(t.n2(), parser.normals()[0]);
+            assert_eq!(t.n3(), parser.normals()[1]);
+        }
+    }
+
+    #[test]
+    fn texture_records_may_omit_v() {
+        let parser = ObjParser::parse("vt 0.5\nvt 0.25 0.75 0").unwrap();
+
+        assert_eq!(
+            parser.texture_coords(),
+            &[
+                (Float::new(0.5), Float::new(0.0)),
+                (Float::new(0.25), Float::new(0.75))
+            ]
+        );
+    }
+
+    #[test]
+    fn faces_keep_their_texture_coordinates() {
+        let input = "v 0 1 0\nv -1 0 0\nv 1 0 0\nv 1 1 0\n\
+                     vn 0 0 1\nvt 0.5 1\nvt 0 0\nvt 1 0\n\
+                     f 1/1 2/2 3/3\n\
+                     f 1/1/1 2/2/1 3/3/1\n\
+                     f 1/1 3/3 4";
+        let parser = ObjParser::parse(input).unwrap();
+        let group = parser.default_group();
+        let expected = Some([
+            parser.texture_coords()[0],
+            parser.texture_coords()[1],
+            parser.texture_coords()[2],
+        ]);
+
+        assert_eq!(
+            downcast::<Triangle>(group[0].as_ref())
+                .unwrap()
+                .texture_coords(),
+            expected
+        );
+        assert_eq!(
+            downcast::<SmoothTriangle>(group[1].as_ref())
+                .unwrap()
+                .texture_coords(),
+            expected
+        );
+        assert_eq!(
+            downcast::<Triangle>(group[2].as_ref())
+                .unwrap()
+                .texture_coords(),
+            None
+        );
+        assert_eq!(
+            group[0].texture_coords_at(point(0.0, 0.0, 0.0)),
+            Some((Float::new(0.5), Float::new(0.0)))
+        );
+    }
+
+    #[test]
+    fn faces_without_every_normal_are_flat() {
+        let parser = ObjParser::parse("v 0 1 0\nv -1 0 0\nv 1 0 0\nvn 0 0 1\nf 1//1 2 3/").unwrap();
+
+        assert!(downcast::<Triangle>(parser.default_group()[0].as_ref()).is_some());
+    }
+
+    #[test]
+    fn resolves_negative_indices_from_the_end() {
+        let parser = ObjParser::parse("v 0 1 0\nv -1 0 0\nv 1 0 0\nf -3 -2 -1").unwrap();
+        let t = triangle(parser.default_group()[0].as_ref());
+
+        assert_eq!(t.p1(), parser.vertices()[0]);
+        assert_eq!(t.p3(), parser.vertices()[2]);
+    }
+
+    #[test]
+    fn rejects_malformed_records() {
+        assert!(ObjParser::parse("v 1 two 3").is_err());
+        assert!(ObjParser::parse("v 1 2").is_err());
+        assert!(ObjParser::parse("vt").is_err());
+        assert!(ObjParser::parse("vt 0.5 high").is_err());
+        assert!(ObjParser::parse("v 0 1 0\nv -1 0 0\nf 1 2 3").is_err());
+        assert!(ObjParser::parse("v 0 1 0\nv -1 0 0\nf 1 2").is_err());
+        assert!(ObjParser::parse("v 0 1 0\nv -1 0 0\nv 1 0 0\nf 0 1 2").is_err());
+    }
+
+    #[test]
+    fn assigns_materials_from_loaded_libraries() {
+        let input = "mtllib shiny.mtl\n\
+                     v 0 1 0\nv -1 0 0\nv 1 0 0\n\
+                     f 1 2 3\n\
+                     usemtl Red\nf 1 2 3\n\
+                     usemtl Missing\nf 1 2 3";
+        let mut requested = vec![];
+        let parser = ObjParser::parse_with_loader(input, |file| {
+            requested.push(file.to_string());
+            MtlLibrary::parse("newmtl Red\nKd 1 0 0\nNs 50")
+        })
+        .unwrap();
+        let red = parser.material("Red").unwrap().to_material();
+        let group = parser.default_group();
+
+        assert_eq!(requested, vec![String::from("shiny.mtl")]);
+        assert_eq!(*group[0].material(), Material::default());
+        assert_eq!(*group[1].material(), red);
+        assert_eq!(red.shininess(), Float::new(50.0));
+        assert_eq!(*group[2].material(), Material::default());
+        assert_eq!(parser.ignored(), &[(8, String::from("usemtl Missing"))]);
+    }
+
+    #[test]
+    fn skips_libraries_that_fail_to_load() {
+        let input = "mtllib missing.mtl found.mtl\n\
+                     v 0 1 0\nv -1 0 0\nv 1 0 0\n\
+                     usemtl Green\nf 1 2 3";
+        let parser = ObjParser::parse_with_loader(input, |file| match file {
+            "found.mtl" => MtlLibrary::parse("newmtl Green\nKd 0 1 0"),
+            _ => Err(format!("{} not found", file)),
+        })
+        .unwrap();
+
+        assert_eq!(
+            parser.ignored(),
+            &[(
+                1,
+                String::from("mtllib missing.mtl found.mtl: missing.mtl not found")
+            )]
+        );
+        assert_eq!(
+            *parser.default_group()[0].material(),
+            parser.material("Green").unwrap().to_material()
+        );
+    }
+
+    #[test]
+    fn loads_libraries_next_to_the_obj_file() {
+        let directory = std::env::temp_dir().join(format!("obj_tests_{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("scene.mtl"), "newmtl Blue\nKd 0 0 1").unwrap();
+        std::fs::write(
+            directory.join("scene.obj"),
+            "mtllib scene.mtl\nv 0 1 0\nv -1 0 0\nv 1 0 0\nusemtl Blue\nf 1 2 3",
+        )
+        .unwrap();
+
+        let parser = ObjParser::from_file(directory.join("scene.obj"));
+        std::fs::remove_dir_all(&directory).unwrap();
+        let parser = parser.unwrap();
+
+        assert_eq!(
+            parser.default_group()[0].material().color(),
+            Color::new(Float::new(0.0), Float::new(0.0), Float::new(1.0))
+        );
+    }
+
+    #[test]
+    fn loads_diffuse_maps_next_to_the_library() {
+        let directory =
+            std::env::temp_dir().join(format!("obj_tests_texture_{}", std::process::id()));
+        std::fs::create_dir_all(directory.join("textures")).unwrap();
+        std::fs::write(
+            directory.join("textures/scene.mtl"),
+            "newmtl Painted\nmap_Kd painted.ppm",
+        )
+        .unwrap();
+        std::fs::write(
+            directory.join("textures/painted.ppm"),
+            "P3\n2 1\n255\n0 0 255  255 255 0",
+        )
+        .unwrap();
+        std::fs::write(
+            directory.join("scene.obj"),
+            "mtllib textures/scene.mtl\n\
+             v 0 0 0\nv 1 0 0\nv 0 1 0\n\
+             vt 0 0\nvt 1 0\nvt 0 1\n\
+             usemtl Painted\nf 1/1 2/2 3/3",
+        )
+        .unwrap();
+
+        let parser = ObjParser::from_file(directory.join("scene.obj"));
+        std::fs::remove_dir_all(&directory).unwrap();
+        let parser = parser.unwrap();
+        let triangle = parser.default_group()[0].as_ref();
+
+        assert!(parser.ignored().is_empty());
+        assert_eq!(
+            triangle
+                .material()
+                .color_at(triangle, point(0.9, 0.05, 0.0)),
+            Color::new(Float::new(1.0), Float::new(1.0), Float::new(0.0))
+        );
+    }
+
+    #[test]
+    fn a_missing_library_file_does_not_fail_the_load() {
+        let directory =
+            std::env::temp_dir().join(format!("obj_tests_missing_{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(
+            directory.join("scene.obj"),
+            "mtllib gone.mtl\nv 0 1 0\nv -1 0 0\nv 1 0 0\nusemtl Blue\nf 1 2 3",
+        )
+        .unwrap();
+
+        let parser = ObjParser::from_file(directory.join("scene.obj"));
+        std::fs::remove_dir_all(&directory).unwrap();
+        let parser = parser.unwrap();
+
+        assert_eq!(parser.ignored().len(), 2);
+        assert!(parser.ignored()[0].1.starts_with("mtllib gone.mtl: "));
+        assert_eq!(*parser.default_group()[0].material(), Material::default());
+    }
+}
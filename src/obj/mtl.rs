@@ -0,0 +1,417 @@
+use super::{parse_float, parse_floats};
+use crate::elementary::float::Float;
+use crate::engine::canvas::Canvas;
+use crate::engine::color::Color;
+use crate::engine::material::Material;
+use crate::engine::pattern::mapping::UvMapping;
+use crate::engine::pattern::texture_map::TextureMap;
+use crate::engine::pattern::uv::UvImage;
+
+use std::path::Path;
+
+/// One `newmtl` entry of a Wavefront MTL file. Statements a material leaves
+/// out keep values that reproduce `Material::default()`; for `Ka` that is
+/// white, which `to_material` reads as the default ambient level.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MtlMaterial {
+    name: String,
+    ambient: Color,
+    diffuse: Color,
+    specular: Color,
+    shininess: Float,
+    dissolve: Float,
+    optical_density: Float,
+    diffuse_map: Option<String>,
+    diffuse_texture: Option<Canvas>,
+}
+
+impl MtlMaterial {
+    pub fn new(name: &str) -> MtlMaterial {
+        let defaults = Material::default();
+        let grey = |value: Float| Color::new(value, value, value);
+
+        MtlMaterial {
+            name: name.to_string(),
+            ambient: grey(Float::new(1.0)),
+            diffuse: defaults.color(),
+            specular: grey(defaults.specular()),
+            shininess: defaults.shininess(),
+            dissolve: Float::new(1.0),
+            optical_density: Float::new(1.0),
+            diffuse_map: None,
+            diffuse_texture: None,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// `Ka`
+    pub fn ambient(&self) -> Color {
+        self.ambient
+    }
+
+    /// `Kd`
+    pub fn diffuse(&self) -> Color {
+        self.diffuse
+    }
+
+    /// `Ks`
+    pub fn specular(&self) -> Color {
+        self.specular
+    }
+
+    /// `Ns`
+    pub fn shininess(&self) -> Float {
+        self.shininess
+    }
+
+    /// `d`, or one minus `Tr`; 1 is fully opaque.
+    pub fn dissolve(&self) -> Float {
+        self.dissolve
+    }
+
+    /// `Ni`
+    pub fn optical_density(&self) -> Float {
+        self.optical_density
+    }
+
+    /// The texture file named by `map_Kd`, as written in the library.
+    pub fn diffuse_map(&self) -> Option<&str> {
+        self.diffuse_map.as_deref()
+    }
+
+    /// The image the `map_Kd` file was loaded into, if it could be.
+    pub fn diffuse_texture(&self) -> Option<&Canvas> {
+        self.diffuse_texture.as_ref()
+    }
+
+    /// Converts to the renderer's material. `Kd` becomes the surface color
+    /// and the mean of `Ks` our scalar specular term. `Ka` is a reflectance
+    /// meant to scale a scene's ambient light, which exporters usually set
+    /// to 1 while leaving that light dim, so its mean scales the default
+    /// ambient level rather than replacing it. Dissolve becomes
+    /// transparency and optical density the refractive index. A loaded
+    /// diffuse texture replaces the `Kd` color, sampled at the shape's own
+    /// texture coordinates or mapped onto it planarly without them.
+    pub fn to_material(&self) -> Material {
+        let mut material = Material::default();
+        material.set_color(self.diffuse);
+        material.set_ambient(mean(self.ambient) * Material::default().ambient());
+        material.set_specular(mean(self.specular));
+        material.set_shininess(self.shininess);
+        material.set_transparency(Float::new(1.0) - self.dissolve);
+        material.set_refractive_index(self.optical_density);
+        if let Some(texture) = &self.diffuse_texture {
+            material.set_pattern(TextureMap::new(
+                UvImage::new(texture.clone()),
+                UvMapping::Planar,
+            ));
+        }
+
+        material
+    }
+}
+
+fn mean(color: Color) -> Float {
+    (color.r() + color.g() + color.b()) / Float::new(3.0)
+}
+
+/// The materials declared by a Wavefront MTL file.
+#[derive(Debug, Default)]
+pub struct MtlLibrary {
+    materials: Vec<MtlMaterial>,
+    ignored: Vec<(usize, String)>,
+}
+
+impl MtlLibrary {
+    /// Parses an MTL file held in memory. There is nowhere to find `map_Kd`
+    /// images from, so only their file names are kept.
+    pub fn parse(input: &str) -> Result<MtlLibrary, String> {
+        MtlLibrary::parse_with_loader(input, |_| Ok(None))
+    }
+
+    /// Parses an MTL file, handing each image named by `map_Kd` to `load`.
+    pub fn parse_with_loader<F>(input: &str, mut load: F) -> Result<MtlLibrary, String>
+    where
+        F: FnMut(&str) -> Result<Option<Canvas>, String>,
+    {
+        let mut library = MtlLibrary::default();
+
+        for (index, line) in input.lines().enumerate() {
+            let line_number = index + 1;
+            let mut tokens = line.split_whitespace();
+            let statement = tokens.next();
+
+            if statement == Some("newmtl") {
+                let name = tokens.collect::<Vec<&str>>().join(" ");
+                library.materials.push(MtlMaterial::new(&name));
+                continue;
+            }
+
+            let material = match (statement, library.materials.last_mut()) {
+                (Some("Ka" | "Kd" | "Ks" | "Ns" | "d" | "Tr" | "Ni" | "map_Kd"), None) => {
+                    return Err(format!("line {}: no material declared yet", line_number));
+                }
+                (_, Some(material)) => material,
+                (_, None) => {
+                    library.ignored.push((line_number, line.to_string()));
+                    continue;
+                }
+            };
+
+            match statement {
+                Some("Ka") => material.ambient = parse_color(tokens, line_number)?,
+                Some("Kd") => material.diffuse = parse_color(tokens, line_number)?,
+                Some("Ks") => material.specular = parse_color(tokens, line_number)?,
+                Some("Ns") => [material.shininess] = parse_floats(tokens, line_number)?,
+                Some("d") => [material.dissolve] = parse_floats(tokens, line_number)?,
+                Some("Tr") => {
+                    let [transparency] = parse_floats(tokens, line_number)?;
+                    material.dissolve = Float::new(1.0) - transparency;
+                }
+                Some("Ni") => [material.optical_density] = parse_floats(tokens, line_number)?,
+                Some("map_Kd") => {
+                    // options such as `-s 1 1 1` may precede the file name
+                    let file = tokens
+                        .last()
+                        .ok_or_else(|| format!("line {}: map_Kd needs a file", line_number))?;
+                    material.diffuse_map = Some(file.to_string());
+                    // an image that fails to load leaves the `Kd` color
+                    match load(file) {
+                        Ok(texture) => material.diffuse_texture = texture,
+                        Err(error) => library
+                            .ignored
+                            .push((line_number, format!("{}: {}", line, error))),
+                    }
+                }
+                _ => library.ignored.push((line_number, line.to_string())),
+            }
+        }
+
+        Ok(library)
+    }
+
+    /// Reads an MTL file, loading `map_Kd` images relative to its directory.
+    /// Only plain PPM images can be read.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<MtlLibrary, String> {
+        let path = path.as_ref();
+        let input = std::fs::read_to_string(path)
+            .map_err(|error| format!("{}: {}", path.display(), error))?;
+        let directory = path.parent().unwrap_or_else(|| Path::new(""));
+
+        MtlLibrary::parse_with_loader(&input, |file| {
+            let image =
+                std::fs::read_to_string(directory.join(file)).map_err(|error| error.to_string())?;
+
+            Canvas::from_ppm(&image).map(Some)
+        })
+        .map_err(|error| format!("{}: {}", path.display(), error))
+    }
+
+    pub fn materials(&self) -> &[MtlMaterial] {
+        &self.materials
+    }
+
+    pub fn material(&self, name: &str) -> Option<&MtlMaterial> {
+        self.materials.iter().find(|material| material.name == name)
+    }
+
+    /// Lines that were not understood, with their 1-based line numbers.
+    pub fn ignored(&self) -> &[(usize, String)] {
+        &self.ignored
+    }
+}
+
+/// Reads `r g b`, or a single value that stands for all three.
+fn parse_color<'a>(
+    tokens: impl Iterator<Item = &'a str>,
+    line_number: usize,
+) -> Result<Color, String> {
+    let values = tokens
+        .map(|token| parse_float(token, line_number))
+        .collect::<Result<Vec<Float>, String>>()?;
+
+    match values[..] {
+        [value] => Ok(Color::new(value, value, value)),
+        [r, g, b] => Ok(Color::new(r, g, b)),
+        _ => Err(format!("line {}: expected 1 or 3 numbers", line_number)),
+    }
+}
+
+#[cfg(test)]
+mod mtl_tests {
+    use super::Canvas;
+    use super::Float;
+    use super::Material;
+    use super::MtlLibrary;
+    use crate::shape::triangle::Triangle;
+    use crate::test_helpers::{color, point};
+
+    #[test]
+    fn parses_material_statements() {
+        let input = "# a comment\n\
+                     newmtl Brass\n\
+                     Ka 0.3 0.2 0.1\n\
+                     Kd 0.8 0.6 0.2\n\
+                     Ks 0.9 0.9 0.6\n\
+                     Ns 96\n\
+                     d 0.5\n\
+                     Ni 1.5\n\
+                     illum 2\n\
+                     map_Kd -s 2 2 1 brass.ppm";
+        let library = MtlLibrary::parse(input).unwrap();
+        let brass = library.material("Brass").unwrap();
+
+        assert_eq!(brass.ambient(), color(0.3, 0.2, 0.1));
+        assert_eq!(brass.diffuse(), color(0.8, 0.6, 0.2));
+        assert_eq!(brass.specular(), color(0.9, 0.9, 0.6));
+        assert_eq!(brass.shininess(), Float::new(96.0));
+        assert_eq!(brass.dissolve(), Float::new(0.5));
+        assert_eq!(brass.optical_density(), Float::new(1.5));
+        assert_eq!(brass.diffuse_map(), Some("brass.ppm"));
+        assert_eq!(
+            library.ignored(),
+            &[
+                (1, String::from("# a comment")),
+                (9, String::from("illum 2"))
+            ]
+        );
+    }
+
+    #[test]
+    fn a_single_value_sets_every_channel() {
+        let library = MtlLibrary::parse("newmtl Grey\nKa 0.2\nKd 0.5\nKs 1").unwrap();
+        let grey = library.material("Grey").unwrap();
+
+        assert_eq!(grey.ambient(), color(0.2, 0.2, 0.2));
+        assert_eq!(grey.diffuse(), color(0.5, 0.5, 0.5));
+        assert_eq!(grey.specular(), color(1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn keeps_each_material_separate() {
+        let library =
+            MtlLibrary::parse("newmtl Red\nKd 1 0 0\nnewmtl Blue\nKd 0 0 1\nTr 0.25").unwrap();
+
+        assert_eq!(library.materials().len(), 2);
+        assert_eq!(
+            library.material("Red").unwrap().diffuse(),
+            color(1.0, 0.0, 0.0)
+        );
+        assert_eq!(library.material("Red").unwrap().dissolve(), Float::new(1.0));
+        assert_eq!(
+            library.material("Blue").unwrap().diffuse(),
+            color(0.0, 0.0, 1.0)
+        );
+        assert_eq!(
+            library.material("Blue").unwrap().dissolve(),
+            Float::new(0.75)
+        );
+        assert!(library.material("Green").is_none());
+    }
+
+    #[test]
+    fn an_empty_material_converts_to_the_default() {
+        let library = MtlLibrary::parse("newmtl Plain").unwrap();
+
+        assert_eq!(library.materials()[0].to_material(), Material::default());
+    }
+
+    #[test]
+    fn converts_to_a_material() {
+        let library = MtlLibrary::parse(
+            "newmtl Brass\nKa 0.3 0.2 0.1\nKd 0.8 0.6 0.2\nKs 0.9 0.9 0.6\nNs 96\nd 0.25\nNi 1.5",
+        )
+        .unwrap();
+        let material = library.materials()[0].to_material();
+
+        assert_eq!(material.color(), color(0.8, 0.6, 0.2));
+        assert_eq!(material.ambient(), Float::new(0.02));
+        assert_eq!(material.diffuse(), Material::default().diffuse());
+        assert_eq!(material.specular(), Float::new(0.8));
+        assert_eq!(material.shininess(), Float::new(96.0));
+        assert_eq!(material.transparency(), Float::new(0.75));
+        assert_eq!(material.refractive_index(), Float::new(1.5));
+    }
+
+    #[test]
+    fn a_white_ambient_reflectance_keeps_the_default_ambient() {
+        let library = MtlLibrary::parse("newmtl Lit\nKa 1 1 1\nnewmtl Dark\nKa 0 0 0").unwrap();
+
+        assert_eq!(
+            library.material("Lit").unwrap().to_material().ambient(),
+            Material::default().ambient()
+        );
+        assert_eq!(
+            library.material("Dark").unwrap().to_material().ambient(),
+            Float::new(0.0)
+        );
+    }
+
+    #[test]
+    fn loads_diffuse_maps_through_the_loader() {
+        let input = "newmtl Painted\nKd 1 0 0\nmap_Kd painted.ppm\n\
+                     newmtl Faded\nKd 0 1 0\nmap_Kd missing.ppm";
+        let mut requested = vec![];
+        let library = MtlLibrary::parse_with_loader(input, |file| {
+            requested.push(file.to_string());
+            match file {
+                "painted.ppm" => Canvas::from_ppm("P3\n2 1\n255\n0 0 255  255 255 0").map(Some),
+                _ => Err(format!("{} not found", file)),
+            }
+        })
+        .unwrap();
+        let painted = library.material("Painted").unwrap();
+        let faded = library.material("Faded").unwrap();
+
+        assert_eq!(
+            requested,
+            vec![String::from("painted.ppm"), String::from("missing.ppm")]
+        );
+        assert_eq!(painted.diffuse_texture().unwrap().width(), 2);
+        assert!(faded.diffuse_texture().is_none());
+        assert_eq!(faded.diffuse_map(), Some("missing.ppm"));
+        assert_eq!(
+            library.ignored(),
+            &[(6, String::from("map_Kd missing.ppm: missing.ppm not found"))]
+        );
+        assert!(faded.to_material().pattern().is_none());
+        assert_eq!(faded.to_material().color(), color(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn a_diffuse_texture_colors_the_material_by_texture_coordinates() {
+        let library = MtlLibrary::parse_with_loader("newmtl Painted\nmap_Kd painted.ppm", |_| {
+            Canvas::from_ppm("P3\n2 1\n255\n0 0 255  255 255 0").map(Some)
+        })
+        .unwrap();
+        let material = library.materials()[0].to_material();
+        let mut triangle = Triangle::new(
+            point(0.0, 0.0, 0.0),
+            point(1.0, 0.0, 0.0),
+            point(0.0, 1.0, 0.0),
+        );
+        let at = |u: f64, v: f64| (Float::new(u), Float::new(v));
+        triangle.set_texture_coords([at(0.0, 0.0), at(1.0, 0.0), at(0.0, 1.0)]);
+
+        assert_eq!(
+            material.color_at(&triangle, point(0.1, 0.1, 0.0)),
+            color(0.0, 0.0, 1.0)
+        );
+        assert_eq!(
+            material.color_at(&triangle, point(0.9, 0.05, 0.0)),
+            color(1.0, 1.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_statements() {
+        assert!(MtlLibrary::parse("Kd 1 0 0").is_err());
+        assert!(MtlLibrary::parse("newmtl Red\nKd 1 0").is_err());
+        assert!(MtlLibrary::parse("newmtl Red\nKd").is_err());
+        assert!(MtlLibrary::parse("newmtl Red\nNs shiny").is_err());
+        assert!(MtlLibrary::parse("newmtl Red\nmap_Kd").is_err());
+    }
+}
@@ -1,13 +1,136 @@
+use crate::elementary::float::Float;
+use crate::elementary::matrix::Matrix4;
+use crate::elementary::point::Point;
+use crate::elementary::vector::Vector;
+
+#[derive(Debug, Copy, Clone)]
+pub struct Ray {
+    origin: Point,
+    direction: Vector,
+}
+
+impl Ray {
+    pub fn new(origin: Point, direction: Vector) -> Ray {
+        Ray { origin, direction }
+    }
+
+    pub fn origin(&self) -> Point {
+        self.origin
+    }
+
+    pub fn direction(&self) -> Vector {
+        self.direction
+    }
+
+    pub fn position(&self, t: Float) -> Point {
+        self.origin + self.direction * t
+    }
+
+    pub fn transform(&self, matrix: &Matrix4) -> Ray {
+        Ray::new(matrix * self.origin, matrix * self.direction)
+    }
+}
+
+impl PartialEq for Ray {
+    fn eq(&self, other: &Self) -> bool {
+        self.origin == other.origin && self.direction == other.direction
+    }
+}
+
 #[cfg(test)]
 mod ray_tests {
-    use crate::elementary::tuple::Tuple;
-    use crate::elementary::float::Float;
+    use super::Float;
+    use super::Matrix4;
+    use super::Point;
+    use super::Ray;
+    use super::Vector;
+
+    fn matrix(data: [f64; 16]) -> Matrix4 {
+        Matrix4::from_vec(data.iter().map(|v| Float::new(*v)).collect())
+    }
 
     #[test]
-    fn a_tuple_with_w_1_is_a_point() {
-        let result = 2 + 2;
-        assert_eq!(result, 4);
-        // let tuple = Tuple::new(4.3, -4.2, 1.0, 1.0);
-        // let a = Float::new(1.0);
+    fn can_create_a_ray() {
+        let origin = Point::new(Float::new(1.0), Float::new(2.0), Float::new(3.0));
+        let direction = Vector::new(Float::new(4.0), Float::new(5.0), Float::new(6.0));
+        let ray = Ray::new(origin, direction);
+
+        assert_eq!(ray.origin(), origin);
+        assert_eq!(ray.direction(), direction);
+    }
+
+    #[test]
+    fn can_compute_a_point_from_a_distance() {
+        let ray = Ray::new(
+            Point::new(Float::new(2.0), Float::new(3.0), Float::new(4.0)),
+            Vector::new(Float::new(1.0), Float::new(0.0), Float::new(0.0)),
+        );
+
+        assert_eq!(
+            ray.position(Float::new(0.0)),
+            Point::new(Float::new(2.0), Float::new(3.0), Float::new(4.0))
+        );
+        assert_eq!(
+            ray.position(Float::new(1.0)),
+            Point::new(Float::new(3.0), Float::new(3.0), Float::new(4.0))
+        );
+        assert_eq!(
+            ray.position(Float::new(-1.0)),
+            Point::new(Float::new(1.0), Float::new(3.0), Float::new(4.0))
+        );
+        assert_eq!(
+            ray.position(Float::new(2.5)),
+            Point::new(Float::new(4.5), Float::new(3.0), Float::new(4.0))
+        );
+    }
+
+    #[test]
+    fn can_translate_a_ray() {
+        let ray = Ray::new(
+            Point::new(Float::new(1.0), Float::new(2.0), Float::new(3.0)),
+            Vector::new(Float::new(0.0), Float::new(1.0), Float::new(0.0)),
+        );
+        let translation = matrix([
+            1.0, 0.0, 0.0, 3.0, //
+            0.0, 1.0, 0.0, 4.0, //
+            0.0, 0.0, 1.0, 5.0, //
+            0.0, 0.0, 0.0, 1.0,
+        ]);
+
+        let transformed = ray.transform(&translation);
+
+        assert_eq!(
+            transformed.origin(),
+            Point::new(Float::new(4.0), Float::new(6.0), Float::new(8.0))
+        );
+        assert_eq!(
+            transformed.direction(),
+            Vector::new(Float::new(0.0), Float::new(1.0), Float::new(0.0))
+        );
+    }
+
+    #[test]
+    fn can_scale_a_ray() {
+        let ray = Ray::new(
+            Point::new(Float::new(1.0), Float::new(2.0), Float::new(3.0)),
+            Vector::new(Float::new(0.0), Float::new(1.0), Float::new(0.0)),
+        );
+        let scaling = matrix([
+            2.0, 0.0, 0.0, 0.0, //
+            0.0, 3.0, 0.0, 0.0, //
+            0.0, 0.0, 4.0, 0.0, //
+            0.0, 0.0, 0.0, 1.0,
+        ]);
+
+        let transformed = ray.transform(&scaling);
+
+        assert_eq!(
+            transformed.origin(),
+            Point::new(Float::new(2.0), Float::new(6.0), Float::new(12.0))
+        );
+        assert_eq!(
+            transformed.direction(),
+            Vector::new(Float::new(0.0), Float::new(3.0), Float::new(0.0))
+        );
     }
 }
@@ -0,0 +1,379 @@
+use crate::elementary::float::Float;
+use crate::elementary::matrix::Matrix4;
+use crate::elementary::point::Point;
+use crate::ray::Ray;
+
+/// An axis-aligned box given by its minimum and maximum corners. A box with
+/// nothing in it has its minimum above its maximum, so adding the first
+/// point or box replaces both corners.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BoundingBox {
+    min: Point,
+    max: Point,
+}
+
+impl BoundingBox {
+    pub fn new(min: Point, max: Point) -> BoundingBox {
+        BoundingBox { min, max }
+    }
+
+    pub fn empty() -> BoundingBox {
+        let infinity = Float::INFINITY;
+
+        BoundingBox::new(
+            Point::new(infinity, infinity, infinity),
+            Point::new(-infinity, -infinity, -infinity),
+        )
+    }
+
+    /// A box covering all of space, for shapes such as planes.
+    pub fn infinite() -> BoundingBox {
+        let infinity = Float::INFINITY;
+
+        BoundingBox::new(
+            Point::new(-infinity, -infinity, -infinity),
+            Point::new(infinity, infinity, infinity),
+        )
+    }
+
+    pub fn min(&self) -> Point {
+        self.min
+    }
+
+    pub fn max(&self) -> Point {
+        self.max
+    }
+
+    pub fn is_empty(&self) -> bool {
+        (0..3).any(|axis| component(self.min, axis).value() > component(self.max, axis).value())
+    }
+
+    /// Whether the box reaches infinitely far along any axis.
+    pub fn is_unbounded(&self) -> bool {
+        !self.is_empty()
+            && (0..3).any(|axis| {
+                component(self.min, axis).value().is_infinite()
+                    || component(self.max, axis).value().is_infinite()
+            })
+    }
+
+    pub fn add_point(&mut self, point: Point) {
+        self.min = Point::new(
+            self.min.x().min(point.x()),
+            self.min.y().min(point.y()),
+            self.min.z().min(point.z()),
+        );
+        self.max = Point::new(
+            self.max.x().max(point.x()),
+            self.max.y().max(point.y()),
+            self.max.z().max(point.z()),
+        );
+    }
+
+    pub fn merge(&mut self, other: &BoundingBox) {
+        if !other.is_empty() {
+            self.add_point(other.min);
+            self.add_point(other.max);
+        }
+    }
+
+    pub fn contains_point(&self, point: Point) -> bool {
+        (0..3).all(|axis| {
+            let value = component(point, axis);
+            component(self.min, axis) <= value && value <= component(self.max, axis)
+        })
+    }
+
+    pub fn contains_box(&self, other: &BoundingBox) -> bool {
+        self.contains_point(other.min) && self.contains_point(other.max)
+    }
+
+    pub fn centroid(&self) -> Point {
+        let half = Float::new(0.5);
+
+        Point::new(
+            (self.min.x() + self.max.x()) * half,
+            (self.min.y() + self.max.y()) * half,
+            (self.min.z() + self.max.z()) * half,
+        )
+    }
+
+    /// The box enclosing this one once `transform` has been applied to it.
+    /// Each axis of the result is built from the range every input axis
+    /// contributes to it, which matches transforming all eight corners but
+    /// also holds for unbounded boxes: axes a matrix entry of zero leaves out
+    /// cannot spread infinity into the result.
+    pub fn transform(&self, transform: &Matrix4) -> BoundingBox {
+        if self.is_empty() {
+            return *self;
+        }
+
+        let mut min = [Float::new(0.0); 3];
+        let mut max = [Float::new(0.0); 3];
+        for row in 0..3 {
+            min[row] = transform.value_at(row, 3);
+            max[row] = transform.value_at(row, 3);
+
+            for col in 0..3 {
+                let scale = transform.value_at(row, col);
+                if scale.value() == 0.0 {
+                    continue;
+                }
+
+                let a = scale * component(self.min, col);
+                let b = scale * component(self.max, col);
+                min[row] = min[row] + a.min(b);
+                max[row] = max[row] + a.max(b);
+            }
+        }
+
+        BoundingBox::new(
+            Point::new(min[0], min[1], min[2]),
+            Point::new(max[0], max[1], max[2]),
+        )
+    }
+
+    /// Whether the ray's line passes through the box, in the same units as
+    /// the box. Like shape intersections, this includes hits behind the ray.
+    pub fn intersects(&self, ray: &Ray) -> bool {
+        let mut tmin = -Float::INFINITY;
+        let mut tmax = Float::INFINITY;
+
+        for axis in 0..3 {
+            let (axis_min, axis_max) = check_axis(
+                component(ray.origin(), axis),
+                component_of_direction(ray, axis),
+                component(self.min, axis),
+                component(self.max, axis),
+            );
+            tmin = tmin.max(axis_min);
+            tmax = tmax.min(axis_max);
+        }
+
+        tmin.value() <= tmax.value()
+    }
+}
+
+impl Default for BoundingBox {
+    fn default() -> Self {
+        BoundingBox::empty()
+    }
+}
+
+/// The coordinate of `point` along axis 0 (x), 1 (y) or 2 (z).
+pub(super) fn component(point: Point, axis: usize) -> Float {
+    match axis {
+        0 => point.x(),
+        1 => point.y(),
+        _ => point.z(),
+    }
+}
+
+fn component_of_direction(ray: &Ray, axis: usize) -> Float {
+    match axis {
+        0 => ray.direction().x(),
+        1 => ray.direction().y(),
+        _ => ray.direction().z(),
+    }
+}
+
+fn check_axis(origin: Float, direction: Float, min: Float, max: Float) -> (Float, Float) {
+    if direction.abs() < Float::EPSILON {
+        // parallel to this pair of faces: the ray is always or never between them
+        return if min <= origin && origin <= max {
+            (-Float::INFINITY, Float::INFINITY)
+        } else {
+            (Float::INFINITY, -Float::INFINITY)
+        };
+    }
+
+    let t1 = (min - origin) / direction;
+    let t2 = (max - origin) / direction;
+
+    (t1.min(t2), t1.max(t2))
+}
+
+#[cfg(test)]
+mod bounds_tests {
+    use super::BoundingBox;
+    use super::Float;
+    use super::Matrix4;
+    use super::Ray;
+    use crate::test_helpers::{point, vector};
+    use std::f64::consts::{FRAC_1_SQRT_2, PI, SQRT_2};
+
+    fn unit_box() -> BoundingBox {
+        BoundingBox::new(point(-1.0, -1.0, -1.0), point(1.0, 1.0, 1.0))
+    }
+
+    #[test]
+    fn creating_an_empty_bounding_box() {
+        let bounds = BoundingBox::empty();
+
+        assert!(bounds.is_empty());
+        assert!(!bounds.is_unbounded());
+        assert_eq!(bounds.min().x(), Float::INFINITY);
+        assert_eq!(bounds.max().x(), -Float::INFINITY);
+    }
+
+    #[test]
+    fn adding_points_to_an_empty_bounding_box() {
+        let mut bounds = BoundingBox::empty();
+        bounds.add_point(point(-5.0, 2.0, 0.0));
+        bounds.add_point(point(7.0, 0.0, -3.0));
+
+        assert_eq!(bounds.min(), point(-5.0, 0.0, -3.0));
+        assert_eq!(bounds.max(), point(7.0, 2.0, 0.0));
+    }
+
+    #[test]
+    fn merging_one_bounding_box_into_another() {
+        let mut bounds = BoundingBox::new(point(-5.0, -2.0, 0.0), point(7.0, 4.0, 4.0));
+        bounds.merge(&BoundingBox::new(
+            point(8.0, -7.0, -2.0),
+            point(14.0, 2.0, 8.0),
+        ));
+        bounds.merge(&BoundingBox::empty());
+
+        assert_eq!(bounds.min(), point(-5.0, -7.0, -2.0));
+        assert_eq!(bounds.max(), point(14.0, 4.0, 8.0));
+    }
+
+    #[test]
+    fn checking_whether_a_box_contains_a_point() {
+        let bounds = BoundingBox::new(point(5.0, -2.0, 0.0), point(11.0, 4.0, 7.0));
+        let cases = [
+            (point(5.0, -2.0, 0.0), true),
+            (point(11.0, 4.0, 7.0), true),
+            (point(8.0, 1.0, 3.0), true),
+            (point(3.0, 0.0, 3.0), false),
+            (point(8.0, -4.0, 3.0), false),
+            (point(8.0, 1.0, -1.0), false),
+            (point(13.0, 1.0, 3.0), false),
+            (point(8.0, 5.0, 3.0), false),
+            (point(8.0, 1.0, 8.0), false),
+        ];
+
+        for (p, expected) in cases {
+            assert_eq!(bounds.contains_point(p), expected);
+        }
+    }
+
+    #[test]
+    fn checking_whether_a_box_contains_a_box() {
+        let bounds = BoundingBox::new(point(5.0, -2.0, 0.0), point(11.0, 4.0, 7.0));
+        let cases = [
+            (point(5.0, -2.0, 0.0), point(11.0, 4.0, 7.0), true),
+            (point(6.0, -1.0, 1.0), point(10.0, 3.0, 6.0), true),
+            (point(4.0, -3.0, -1.0), point(10.0, 3.0, 6.0), false),
+            (point(6.0, -1.0, 1.0), point(12.0, 5.0, 8.0), false),
+        ];
+
+        for (min, max, expected) in cases {
+            assert_eq!(bounds.contains_box(&BoundingBox::new(min, max)), expected);
+        }
+    }
+
+    #[test]
+    fn transforming_a_bounding_box() {
+        let transform = Matrix4::rotation_y(Float::new(PI / 4.0)).rotate_x(Float::new(PI / 4.0));
+        let bounds = unit_box().transform(&transform);
+        let spread = 1.0 + FRAC_1_SQRT_2;
+
+        assert_eq!(bounds.min(), point(-SQRT_2, -spread, -spread));
+        assert_eq!(bounds.max(), point(SQRT_2, spread, spread));
+    }
+
+    #[test]
+    fn transforming_unbounded_boxes() {
+        let infinity = f64::INFINITY;
+        let plane = BoundingBox::new(
+            point(-infinity, 0.0, -infinity),
+            point(infinity, 0.0, infinity),
+        );
+        let raised = plane.transform(&Matrix4::translation(
+            Float::new(0.0),
+            Float::new(2.0),
+            Float::new(0.0),
+        ));
+
+        assert!(raised.is_unbounded());
+        assert_eq!(raised.min().y(), Float::new(2.0));
+        assert_eq!(raised.max().y(), Float::new(2.0));
+        assert_eq!(
+            plane.transform(&Matrix4::rotation_z(Float::new(PI / 4.0))),
+            BoundingBox::infinite()
+        );
+        assert_eq!(
+            plane.transform(&Matrix4::rotation_y(Float::new(PI / 4.0))),
+            plane
+        );
+        assert!(BoundingBox::empty()
+            .transform(&Matrix4::rotation_z(Float::new(PI / 4.0)))
+            .is_empty());
+    }
+
+    #[test]
+    fn intersecting_a_ray_with_a_bounding_box_at_the_origin() {
+        let bounds = unit_box();
+        let cases = [
+            (point(5.0, 0.5, 0.0), vector(-1.0, 0.0, 0.0), true),
+            (point(-5.0, 0.5, 0.0), vector(1.0, 0.0, 0.0), true),
+            (point(0.5, 5.0, 0.0), vector(0.0, -1.0, 0.0), true),
+            (point(0.5, -5.0, 0.0), vector(0.0, 1.0, 0.0), true),
+            (point(0.5, 0.0, 5.0), vector(0.0, 0.0, -1.0), true),
+            (point(0.5, 0.0, -5.0), vector(0.0, 0.0, 1.0), true),
+            (point(0.0, 0.5, 0.0), vector(0.0, 0.0, 1.0), true),
+            (point(-2.0, 0.0, 0.0), vector(2.0, 4.0, 6.0), false),
+            (point(0.0, -2.0, 0.0), vector(6.0, 2.0, 4.0), false),
+            (point(0.0, 0.0, -2.0), vector(4.0, 6.0, 2.0), false),
+            (point(2.0, 0.0, 2.0), vector(0.0, 0.0, -1.0), false),
+            (point(0.0, 2.0, 2.0), vector(0.0, -1.0, 0.0), false),
+            (point(2.0, 2.0, 0.0), vector(-1.0, 0.0, 0.0), false),
+        ];
+
+        for (origin, direction, expected) in cases {
+            let ray = Ray::new(origin, direction.normalize());
+            assert_eq!(bounds.intersects(&ray), expected);
+        }
+    }
+
+    #[test]
+    fn intersecting_a_ray_with_a_non_cubic_bounding_box() {
+        let bounds = BoundingBox::new(point(5.0, -2.0, 0.0), point(11.0, 4.0, 7.0));
+        let cases = [
+            (point(15.0, 1.0, 2.0), vector(-1.0, 0.0, 0.0), true),
+            (point(-5.0, -1.0, 4.0), vector(1.0, 0.0, 0.0), true),
+            (point(7.0, 6.0, 5.0), vector(0.0, -1.0, 0.0), true),
+            (point(9.0, -5.0, 6.0), vector(0.0, 1.0, 0.0), true),
+            (point(8.0, 2.0, 12.0), vector(0.0, 0.0, -1.0), true),
+            (point(6.0, 0.0, -5.0), vector(0.0, 0.0, 1.0), true),
+            (point(8.0, 1.0, 3.5), vector(0.0, 0.0, 1.0), true),
+            (point(9.0, -1.0, -8.0), vector(2.0, 4.0, 6.0), false),
+            (point(8.0, 3.0, -4.0), vector(6.0, 2.0, 4.0), false),
+            (point(9.0, -1.0, -2.0), vector(4.0, 6.0, 2.0), false),
+            (point(4.0, 0.0, 9.0), vector(0.0, 0.0, -1.0), false),
+            (point(8.0, 6.0, -1.0), vector(0.0, -1.0, 0.0), false),
+            (point(12.0, 5.0, 4.0), vector(-1.0, 0.0, 0.0), false),
+        ];
+
+        for (origin, direction, expected) in cases {
+            let ray = Ray::new(origin, direction.normalize());
+            assert_eq!(bounds.intersects(&ray), expected);
+        }
+    }
+
+    #[test]
+    fn rays_meet_unbounded_boxes_they_cross() {
+        let infinity = f64::INFINITY;
+        let plane = BoundingBox::new(
+            point(-infinity, 0.0, -infinity),
+            point(infinity, 0.0, infinity),
+        );
+
+        assert!(plane.intersects(&Ray::new(point(3.0, 5.0, -2.0), vector(0.0, -1.0, 0.0))));
+        assert!(!plane.intersects(&Ray::new(point(3.0, 5.0, -2.0), vector(1.0, 0.0, 0.0))));
+        assert!(!BoundingBox::empty()
+            .intersects(&Ray::new(point(0.0, 0.0, 0.0), vector(0.0, 0.0, 1.0))));
+    }
+}
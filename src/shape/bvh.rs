@@ -0,0 +1,224 @@
+use super::bounds::{component, BoundingBox};
+use crate::ray::Ray;
+
+/// The most items kept in one leaf before it is split in two.
+const LEAF_SIZE: usize = 4;
+
+#[derive(Debug)]
+enum BvhNode {
+    Leaf {
+        bounds: BoundingBox,
+        start: usize,
+        end: usize,
+    },
+    Branch {
+        bounds: BoundingBox,
+        left: usize,
+        right: usize,
+    },
+}
+
+impl BvhNode {
+    fn bounds(&self) -> &BoundingBox {
+        match self {
+            BvhNode::Leaf { bounds, .. } | BvhNode::Branch { bounds, .. } => bounds,
+        }
+    }
+}
+
+/// A bounding volume hierarchy over a list of boxes, used to find which
+/// items a ray could hit without testing every one. The tree is built by
+/// splitting at the median centroid along the widest axis. Items with
+/// unbounded boxes, such as planes, stay outside the tree and are always
+/// reported.
+#[derive(Debug)]
+pub struct Bvh {
+    nodes: Vec<BvhNode>,
+    indices: Vec<usize>,
+    unbounded: Vec<usize>,
+    bounds: BoundingBox,
+}
+
+impl Bvh {
+    /// Builds the hierarchy over `bounds`, where `bounds[i]` encloses item `i`.
+    pub fn build(bounds: &[BoundingBox]) -> Bvh {
+        let mut total = BoundingBox::empty();
+        for item in bounds {
+            total.merge(item);
+        }
+
+        let (unbounded, mut indices): (Vec<usize>, Vec<usize>) =
+            (0..bounds.len()).partition(|&i| bounds[i].is_unbounded());
+        indices.retain(|&i| !bounds[i].is_empty());
+
+        let mut bvh = Bvh {
+            nodes: vec![],
+            indices,
+            unbounded,
+            bounds: total,
+        };
+        if !bvh.indices.is_empty() {
+            bvh.build_node(bounds, 0, bvh.indices.len());
+        }
+
+        bvh
+    }
+
+    /// The box enclosing every item.
+    pub fn bounds(&self) -> BoundingBox {
+        self.bounds
+    }
+
+    /// The items whose boxes the ray passes through, in no particular order.
+    pub fn candidates(&self, ray: &Ray) -> Vec<usize> {
+        let mut candidates = self.unbounded.clone();
+        let mut stack = if self.nodes.is_empty() {
+            vec![]
+        } else {
+            vec![0]
+        };
+
+        while let Some(node) = stack.pop() {
+            let node = &self.nodes[node];
+            if !node.bounds().intersects(ray) {
+                continue;
+            }
+
+            match *node {
+                BvhNode::Leaf { start, end, .. } => {
+                    candidates.extend_from_slice(&self.indices[start..end])
+                }
+                BvhNode::Branch { left, right, .. } => stack.extend([left, right]),
+            }
+        }
+
+        candidates
+    }
+
+    fn build_node(&mut self, bounds: &[BoundingBox], start: usize, end: usize) -> usize {
+        let mut node_bounds = BoundingBox::empty();
+        let mut centroids = BoundingBox::empty();
+        for &i in &self.indices[start..end] {
+            node_bounds.merge(&bounds[i]);
+            centroids.add_point(bounds[i].centroid());
+        }
+
+        let axis = (0..3)
+            .max_by(|&a, &b| extent(&centroids, a).total_cmp(&extent(&centroids, b)))
+            .unwrap();
+
+        // identical centroids cannot be told apart, so they share a leaf
+        let index = self.nodes.len();
+        if end - start <= LEAF_SIZE || extent(&centroids, axis) == 0.0 {
+            self.nodes.push(BvhNode::Leaf {
+                bounds: node_bounds,
+                start,
+                end,
+            });
+            return index;
+        }
+
+        self.indices[start..end].sort_by(|&a, &b| {
+            let a = component(bounds[a].centroid(), axis).value();
+            let b = component(bounds[b].centroid(), axis).value();
+            a.total_cmp(&b)
+        });
+
+        let middle = start + (end - start) / 2;
+        self.nodes.push(BvhNode::Leaf {
+            bounds: node_bounds,
+            start,
+            end,
+        });
+        let left = self.build_node(bounds, start, middle);
+        let right = self.build_node(bounds, middle, end);
+        self.nodes[index] = BvhNode::Branch {
+            bounds: node_bounds,
+            left,
+            right,
+        };
+
+        index
+    }
+}
+
+fn extent(bounds: &BoundingBox, axis: usize) -> f64 {
+    (component(bounds.max(), axis) - component(bounds.min(), axis)).value()
+}
+
+#[cfg(test)]
+mod bvh_tests {
+    use super::BoundingBox;
+    use super::Bvh;
+    use super::Ray;
+    use super::LEAF_SIZE;
+    use crate::test_helpers::{point, vector};
+
+    /// Unit cubes centered at x = 0, 3, 6, ... along the x axis.
+    fn row_of_boxes(count: usize) -> Vec<BoundingBox> {
+        (0..count)
+            .map(|i| {
+                let x = 3.0 * i as f64;
+                BoundingBox::new(point(x - 1.0, -1.0, -1.0), point(x + 1.0, 1.0, 1.0))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn an_empty_hierarchy_has_no_candidates() {
+        let bvh = Bvh::build(&[]);
+        let ray = Ray::new(point(0.0, 0.0, -5.0), vector(0.0, 0.0, 1.0));
+
+        assert!(bvh.bounds().is_empty());
+        assert!(bvh.candidates(&ray).is_empty());
+    }
+
+    #[test]
+    fn the_hierarchy_bounds_every_item() {
+        let bvh = Bvh::build(&row_of_boxes(10));
+
+        assert_eq!(bvh.bounds().min(), point(-1.0, -1.0, -1.0));
+        assert_eq!(bvh.bounds().max(), point(28.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn only_boxes_along_the_ray_are_candidates() {
+        let bvh = Bvh::build(&row_of_boxes(100));
+        let ray = Ray::new(point(21.0, 0.0, -5.0), vector(0.0, 0.0, 1.0));
+
+        let candidates = bvh.candidates(&ray);
+
+        assert!(candidates.contains(&7));
+        assert!(candidates.len() <= LEAF_SIZE);
+    }
+
+    #[test]
+    fn a_ray_along_the_row_sees_every_box() {
+        let bvh = Bvh::build(&row_of_boxes(100));
+        let ray = Ray::new(point(-5.0, 0.0, 0.0), vector(1.0, 0.0, 0.0));
+        let mut candidates = bvh.candidates(&ray);
+        candidates.sort();
+
+        assert_eq!(candidates, (0..100).collect::<Vec<usize>>());
+    }
+
+    #[test]
+    fn unbounded_items_are_always_candidates() {
+        let mut bounds = row_of_boxes(20);
+        bounds.push(BoundingBox::infinite());
+        let bvh = Bvh::build(&bounds);
+        let ray = Ray::new(point(0.0, 50.0, 0.0), vector(0.0, 0.0, 1.0));
+
+        assert_eq!(bvh.bounds(), BoundingBox::infinite());
+        assert_eq!(bvh.candidates(&ray), vec![20]);
+    }
+
+    #[test]
+    fn items_sharing_a_centroid_stay_together() {
+        let bounds = vec![BoundingBox::new(point(-1.0, -1.0, -1.0), point(1.0, 1.0, 1.0)); 10];
+        let bvh = Bvh::build(&bounds);
+        let ray = Ray::new(point(0.0, 0.0, -5.0), vector(0.0, 0.0, 1.0));
+
+        assert_eq!(bvh.candidates(&ray).len(), 10);
+    }
+}
@@ -0,0 +1,277 @@
+use super::bounds::BoundingBox;
+use super::cylinder::check_cap;
+use super::{Shape, ShapeData};
+use crate::elementary::float::Float;
+use crate::elementary::point::Point;
+use crate::elementary::vector::Vector;
+use crate::intersection::Intersection;
+use crate::ray::Ray;
+
+/// A double-napped cone around the object-space y axis whose radius at any
+/// height equals `|y|`, optionally truncated and capped like `Cylinder`.
+#[derive(Debug)]
+pub struct Cone {
+    data: ShapeData,
+    minimum: Float,
+    maximum: Float,
+    closed: bool,
+}
+
+impl Cone {
+    pub fn new() -> Cone {
+        Cone {
+            data: ShapeData::new(),
+            minimum: -Float::INFINITY,
+            maximum: Float::INFINITY,
+            closed: false,
+        }
+    }
+
+    pub fn minimum(&self) -> Float {
+        self.minimum
+    }
+
+    pub fn set_minimum(&mut self, minimum: Float) {
+        self.minimum = minimum;
+    }
+
+    pub fn maximum(&self) -> Float {
+        self.maximum
+    }
+
+    pub fn set_maximum(&mut self, maximum: Float) {
+        self.maximum = maximum;
+    }
+
+    pub fn closed(&self) -> bool {
+        self.closed
+    }
+
+    pub fn set_closed(&mut self, closed: bool) {
+        self.closed = closed;
+    }
+
+    fn intersect_caps<'a>(&'a self, ray: &Ray, xs: &mut Vec<Intersection<'a>>) {
+        if !self.closed || ray.direction().y().abs() < Float::EPSILON {
+            return;
+        }
+
+        for y in [self.minimum, self.maximum] {
+            let t = (y - ray.origin().y()) / ray.direction().y();
+            if check_cap(ray, t, y.abs()) {
+                xs.push(Intersection::new(t, self));
+            }
+        }
+    }
+}
+
+impl Default for Cone {
+    fn default() -> Self {
+        Cone::new()
+    }
+}
+
+impl Shape for Cone {
+    fn data(&self) -> &ShapeData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut ShapeData {
+        &mut self.data
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        let mut xs = vec![];
+        let (origin, direction) = (ray.origin(), ray.direction());
+
+        let a = direction.x() * direction.x() - direction.y() * direction.y()
+            + direction.z() * direction.z();
+        let b = Float::new(2.0) * origin.x() * direction.x()
+            - Float::new(2.0) * origin.y() * direction.y()
+            + Float::new(2.0) * origin.z() * direction.z();
+        let c = origin.x() * origin.x() - origin.y() * origin.y() + origin.z() * origin.z();
+
+        let mut ts = vec![];
+        if a.abs() < Float::EPSILON {
+            // parallel to one of the halves, so the ray crosses the other once
+            if b.abs() >= Float::EPSILON {
+                ts.push(-c / (Float::new(2.0) * b));
+            }
+        } else {
+            let discriminant = b * b - Float::new(4.0) * a * c;
+            // exact, as for spheres: no square root below 0
+            if discriminant.value() >= 0.0 {
+                let t0 = (-b - discriminant.sqrt()) / (Float::new(2.0) * a);
+                let t1 = (-b + discriminant.sqrt()) / (Float::new(2.0) * a);
+                ts.push(t0.min(t1));
+                ts.push(t0.max(t1));
+            }
+        }
+
+        for t in ts {
+            let y = origin.y() + t * direction.y();
+            if self.minimum < y && y < self.maximum {
+                xs.push(Intersection::new(t, self));
+            }
+        }
+
+        self.intersect_caps(ray, &mut xs);
+        xs
+    }
+
+    fn local_normal_at(&self, point: Point, _hit: &Intersection) -> Vector {
+        let zero = Float::new(0.0);
+        let distance = point.x() * point.x() + point.z() * point.z();
+
+        if distance < self.maximum * self.maximum && point.y() >= self.maximum - Float::EPSILON {
+            return Vector::new(zero, Float::new(1.0), zero);
+        } else if distance < self.minimum * self.minimum
+            && point.y() <= self.minimum + Float::EPSILON
+        {
+            return Vector::new(zero, Float::new(-1.0), zero);
+        }
+
+        let mut y = distance.sqrt();
+        if point.y() > zero {
+            y = -y;
+        }
+
+        Vector::new(point.x(), y, point.z())
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        let limit = self.minimum.abs().max(self.maximum.abs());
+
+        BoundingBox::new(
+            Point::new(-limit, self.minimum, -limit),
+            Point::new(limit, self.maximum, limit),
+        )
+    }
+}
+
+#[cfg(test)]
+mod cone_tests {
+    use super::Cone;
+    use super::Float;
+    use super::Intersection;
+    use super::Ray;
+    use super::Shape;
+    use crate::test_helpers::{point, vector};
+
+    #[test]
+    fn intersecting_a_cone_with_a_ray() {
+        let cone = Cone::new();
+        let cases = [
+            (point(0.0, 0.0, -5.0), vector(0.0, 0.0, 1.0), 5.0, 5.0),
+            (
+                point(0.0, 0.0, -5.0),
+                vector(1.0, 1.0, 1.0),
+                8.66025,
+                8.66025,
+            ),
+            (
+                point(1.0, 1.0, -5.0),
+                vector(-0.5, -1.0, 1.0),
+                4.55006,
+                49.44994,
+            ),
+        ];
+
+        for (origin, direction, t0, t1) in cases {
+            let xs = cone.local_intersect(&Ray::new(origin, direction.normalize()));
+
+            assert_eq!(xs.len(), 2);
+            assert_eq!(xs[0].t(), Float::new(t0));
+            assert_eq!(xs[1].t(), Float::new(t1));
+        }
+    }
+
+    #[test]
+    fn ray_just_misses_a_cone() {
+        let cone = Cone::new();
+        let ray = Ray::new(point(1.000001, 1.0, -5.0), vector(0.0, 0.0, 1.0));
+
+        assert!(cone.local_intersect(&ray).is_empty());
+    }
+
+    #[test]
+    fn intersecting_a_cone_with_a_ray_parallel_to_one_of_its_halves() {
+        let cone = Cone::new();
+        let ray = Ray::new(point(0.0, 0.0, -1.0), vector(0.0, 1.0, 1.0).normalize());
+        let xs = cone.local_intersect(&ray);
+
+        assert_eq!(xs.len(), 1);
+        assert_eq!(xs[0].t(), Float::new(0.35355));
+    }
+
+    #[test]
+    fn intersecting_the_caps_of_a_cone() {
+        let mut cone = Cone::new();
+        cone.set_minimum(Float::new(-0.5));
+        cone.set_maximum(Float::new(0.5));
+        cone.set_closed(true);
+        let cases = [
+            (point(0.0, 0.0, -5.0), vector(0.0, 1.0, 0.0), 0),
+            (point(0.0, 0.0, -0.25), vector(0.0, 1.0, 1.0), 2),
+            (point(0.0, 0.0, -0.25), vector(0.0, 1.0, 0.0), 4),
+        ];
+
+        for (origin, direction, count) in cases {
+            let ray = Ray::new(origin, direction.normalize());
+            assert_eq!(cone.local_intersect(&ray).len(), count);
+        }
+    }
+
+    #[test]
+    fn computing_the_normal_vector_on_a_cone() {
+        let cone = Cone::new();
+        let cases = [
+            (point(0.0, 0.0, 0.0), vector(0.0, 0.0, 0.0)),
+            (point(1.0, 1.0, 1.0), vector(1.0, -(2.0_f64.sqrt()), 1.0)),
+            (point(-1.0, -1.0, 0.0), vector(-1.0, 1.0, 0.0)),
+        ];
+        let hit = Intersection::new(Float::new(0.0), &cone);
+
+        for (point, normal) in cases {
+            assert_eq!(cone.local_normal_at(point, &hit), normal);
+        }
+    }
+
+    #[test]
+    fn computing_the_normal_vector_on_the_caps_of_a_cone() {
+        let mut cone = Cone::new();
+        cone.set_minimum(Float::new(-1.0));
+        cone.set_maximum(Float::new(2.0));
+        cone.set_closed(true);
+        let hit = Intersection::new(Float::new(0.0), &cone);
+
+        assert_eq!(
+            cone.local_normal_at(point(0.5, 2.0, 0.0), &hit),
+            vector(0.0, 1.0, 0.0)
+        );
+        assert_eq!(
+            cone.local_normal_at(point(0.2, -1.0, 0.3), &hit),
+            vector(0.0, -1.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn a_cone_has_a_bounding_box() {
+        let unbounded = Cone::new().bounds();
+        let mut cone = Cone::new();
+        cone.set_minimum(Float::new(-5.0));
+        cone.set_maximum(Float::new(3.0));
+        let bounded = cone.bounds();
+
+        assert_eq!(
+            unbounded.min(),
+            point(-f64::INFINITY, -f64::INFINITY, -f64::INFINITY)
+        );
+        assert_eq!(
+            unbounded.max(),
+            point(f64::INFINITY, f64::INFINITY, f64::INFINITY)
+        );
+        assert_eq!(bounded.min(), point(-5.0, -5.0, -5.0));
+        assert_eq!(bounded.max(), point(5.0, 3.0, 5.0));
+    }
+}
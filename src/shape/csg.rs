@@ -0,0 +1,323 @@
+use super::bounds::BoundingBox;
+use super::{Shape, ShapeData};
+use crate::elementary::matrix::Matrix4;
+use crate::elementary::point::Point;
+use crate::elementary::vector::Vector;
+use crate::intersection::{Intersection, Intersections};
+use crate::ray::Ray;
+
+/// How a `Csg` combines the volumes of its two operands.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CsgOperation {
+    /// Everything inside either operand.
+    Union,
+    /// Only what is inside both operands.
+    Intersection,
+    /// What is inside the left operand but not the right.
+    Difference,
+}
+
+impl CsgOperation {
+    /// Whether a hit on one operand lies on the surface of the combined
+    /// shape, given whether it struck the left operand and whether the ray
+    /// was inside each operand at that point.
+    pub fn intersection_allowed(&self, left_hit: bool, in_left: bool, in_right: bool) -> bool {
+        match self {
+            CsgOperation::Union => (left_hit && !in_right) || (!left_hit && !in_left),
+            CsgOperation::Intersection => (left_hit && in_right) || (!left_hit && in_left),
+            CsgOperation::Difference => (left_hit && !in_right) || (!left_hit && in_left),
+        }
+    }
+}
+
+/// Constructive solid geometry: two shapes combined by a set operation.
+/// Rays are intersected with both operands and only the hits that fall on
+/// the surface of the combined volume are kept.
+#[derive(Debug)]
+pub struct Csg {
+    data: ShapeData,
+    operation: CsgOperation,
+    left: Box<dyn Shape>,
+    right: Box<dyn Shape>,
+}
+
+impl Csg {
+    pub fn new<L, R>(operation: CsgOperation, left: L, right: R) -> Csg
+    where
+        L: Shape + 'static,
+        R: Shape + 'static,
+    {
+        Csg::from_boxed(operation, Box::new(left), Box::new(right))
+    }
+
+    pub fn from_boxed(operation: CsgOperation, left: Box<dyn Shape>, right: Box<dyn Shape>) -> Csg {
+        let mut csg = Csg {
+            data: ShapeData::new(),
+            operation,
+            left,
+            right,
+        };
+        csg.set_parent_inverses(vec![]);
+
+        csg
+    }
+
+    pub fn operation(&self) -> CsgOperation {
+        self.operation
+    }
+
+    pub fn left(&self) -> &dyn Shape {
+        self.left.as_ref()
+    }
+
+    pub fn right(&self) -> &dyn Shape {
+        self.right.as_ref()
+    }
+
+    /// Keeps the hits that `intersection_allowed` accepts, walking them in
+    /// order while tracking whether the ray is inside each operand.
+    pub fn filter_intersections<'a>(&self, xs: Intersections<'a>) -> Vec<Intersection<'a>> {
+        let mut in_left = false;
+        let mut in_right = false;
+        let mut filtered = vec![];
+
+        for intersection in xs {
+            let left_hit = self.left.includes(intersection.object());
+            if self
+                .operation
+                .intersection_allowed(left_hit, in_left, in_right)
+            {
+                filtered.push(intersection);
+            }
+
+            if left_hit {
+                in_left = !in_left;
+            } else {
+                in_right = !in_right;
+            }
+        }
+
+        filtered
+    }
+}
+
+impl Shape for Csg {
+    fn data(&self) -> &ShapeData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut ShapeData {
+        &mut self.data
+    }
+
+    fn set_parent_inverses(&mut self, parent_inverses: Vec<Matrix4>) {
+        let mut child_parent_inverses = parent_inverses.clone();
+        child_parent_inverses.push(self.data.inverse);
+        self.data.parent_inverses = parent_inverses;
+
+        self.left.set_parent_inverses(child_parent_inverses.clone());
+        self.right.set_parent_inverses(child_parent_inverses);
+    }
+
+    fn includes(&self, other: &dyn Shape) -> bool {
+        self.left.includes(other) || self.right.includes(other)
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        if !self.bounds().intersects(ray) {
+            return vec![];
+        }
+
+        let mut xs = self.left.intersect(ray).into_iter().collect::<Vec<_>>();
+        xs.extend(self.right.intersect(ray));
+
+        self.filter_intersections(Intersections::new(xs))
+    }
+
+    fn local_normal_at(&self, _point: Point, _hit: &Intersection) -> Vector {
+        unreachable!("a csg has no surface of its own; normals come from its operands")
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        let mut bounds = self.left.parent_space_bounds();
+        // a difference never reaches outside its left operand
+        if self.operation != CsgOperation::Difference {
+            bounds.merge(&self.right.parent_space_bounds());
+        }
+
+        bounds
+    }
+}
+
+#[cfg(test)]
+mod csg_tests {
+    use super::Csg;
+    use super::CsgOperation;
+    use super::Intersection;
+    use super::Intersections;
+    use super::Matrix4;
+    use super::Ray;
+    use super::Shape;
+    use crate::elementary::float::Float;
+    use crate::shape::cube::Cube;
+    use crate::shape::group::Group;
+    use crate::shape::sphere::Sphere;
+    use crate::test_helpers::{downcast, point, vector};
+
+    #[test]
+    fn csg_is_created_with_an_operation_and_two_shapes() {
+        let csg = Csg::new(CsgOperation::Union, Sphere::new(), Cube::new());
+
+        assert_eq!(csg.operation(), CsgOperation::Union);
+        assert!(downcast::<Sphere>(csg.left()).is_some());
+        assert!(downcast::<Cube>(csg.right()).is_some());
+        assert_eq!(csg.left().parent_inverses().len(), 1);
+        assert_eq!(csg.right().parent_inverses().len(), 1);
+    }
+
+    #[test]
+    fn evaluating_the_rule_for_a_csg_operation() {
+        use CsgOperation::{Difference, Intersection, Union};
+
+        let cases = [
+            (Union, true, true, true, false),
+            (Union, true, true, false, true),
+            (Union, true, false, true, false),
+            (Union, true, false, false, true),
+            (Union, false, true, true, false),
+            (Union, false, true, false, false),
+            (Union, false, false, true, true),
+            (Union, false, false, false, true),
+            (Intersection, true, true, true, true),
+            (Intersection, true, true, false, false),
+            (Intersection, true, false, true, true),
+            (Intersection, true, false, false, false),
+            (Intersection, false, true, true, true),
+            (Intersection, false, true, false, true),
+            (Intersection, false, false, true, false),
+            (Intersection, false, false, false, false),
+            (Difference, true, true, true, false),
+            (Difference, true, true, false, true),
+            (Difference, true, false, true, false),
+            (Difference, true, false, false, true),
+            (Difference, false, true, true, true),
+            (Difference, false, true, false, true),
+            (Difference, false, false, true, false),
+            (Difference, false, false, false, false),
+        ];
+
+        for (operation, left_hit, in_left, in_right, expected) in cases {
+            assert_eq!(
+                operation.intersection_allowed(left_hit, in_left, in_right),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn filtering_a_list_of_intersections() {
+        let cases = [
+            (CsgOperation::Union, 0, 3),
+            (CsgOperation::Intersection, 1, 2),
+            (CsgOperation::Difference, 0, 1),
+        ];
+
+        for (operation, x0, x1) in cases {
+            let csg = Csg::new(operation, Sphere::new(), Cube::new());
+            let (s1, s2) = (csg.left(), csg.right());
+            let xs = Intersections::new(vec![
+                Intersection::new(Float::new(1.0), s1),
+                Intersection::new(Float::new(2.0), s2),
+                Intersection::new(Float::new(3.0), s1),
+                Intersection::new(Float::new(4.0), s2),
+            ]);
+            let result = csg.filter_intersections(xs.clone());
+
+            assert_eq!(result.len(), 2);
+            assert_eq!(result[0], xs[x0]);
+            assert_eq!(result[1], xs[x1]);
+        }
+    }
+
+    #[test]
+    fn a_ray_misses_a_csg_object() {
+        let csg = Csg::new(CsgOperation::Union, Sphere::new(), Cube::new());
+        let ray = Ray::new(point(0.0, 2.0, -5.0), vector(0.0, 0.0, 1.0));
+
+        assert!(csg.intersect(&ray).is_empty());
+    }
+
+    #[test]
+    fn a_ray_hits_a_csg_object() {
+        let mut s2 = Sphere::new();
+        s2.set_transform(Matrix4::translation(
+            Float::new(0.0),
+            Float::new(0.0),
+            Float::new(0.5),
+        ));
+        let csg = Csg::new(CsgOperation::Union, Sphere::new(), s2);
+        let ray = Ray::new(point(0.0, 0.0, -5.0), vector(0.0, 0.0, 1.0));
+        let xs = csg.intersect(&ray);
+
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t(), Float::new(4.0));
+        assert!(std::ptr::addr_eq(xs[0].object(), csg.left()));
+        assert_eq!(xs[1].t(), Float::new(6.5));
+        assert!(std::ptr::addr_eq(xs[1].object(), csg.right()));
+    }
+
+    #[test]
+    fn operands_inside_groups_count_as_their_side() {
+        let mut group = Group::new();
+        group.add_child(Sphere::new());
+        let csg = Csg::new(CsgOperation::Difference, group, Cube::new());
+        let inner = downcast::<Group>(csg.left()).unwrap().children()[0].as_ref();
+
+        assert!(csg.left().includes(inner));
+        assert!(!csg.right().includes(inner));
+        assert!(csg.includes(inner));
+        assert_eq!(inner.parent_inverses().len(), 2);
+    }
+
+    #[test]
+    fn carving_a_hole_through_a_cube() {
+        let mut hole = Sphere::new();
+        hole.set_transform(Matrix4::scaling(
+            Float::new(0.5),
+            Float::new(0.5),
+            Float::new(2.0),
+        ));
+        let csg = Csg::new(CsgOperation::Difference, Cube::new(), hole);
+        let through_hole = Ray::new(point(0.0, 0.0, -5.0), vector(0.0, 0.0, 1.0));
+        let beside_hole = Ray::new(point(0.8, 0.0, -5.0), vector(0.0, 0.0, 1.0));
+        let xs = csg.intersect(&beside_hole);
+
+        assert!(csg.intersect(&through_hole).is_empty());
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t(), Float::new(4.0));
+        assert_eq!(xs[1].t(), Float::new(6.0));
+    }
+
+    #[test]
+    fn a_lens_is_the_intersection_of_two_spheres() {
+        let mut front = Sphere::new();
+        front.set_transform(Matrix4::translation(
+            Float::new(0.0),
+            Float::new(0.0),
+            Float::new(0.5),
+        ));
+        let mut back = Sphere::new();
+        back.set_transform(Matrix4::translation(
+            Float::new(0.0),
+            Float::new(0.0),
+            Float::new(-0.5),
+        ));
+        let lens = Csg::new(CsgOperation::Intersection, front, back);
+        let ray = Ray::new(point(0.0, 0.0, -5.0), vector(0.0, 0.0, 1.0));
+        let xs = lens.intersect(&ray);
+
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t(), Float::new(4.5));
+        assert_eq!(xs[1].t(), Float::new(5.5));
+    }
+}
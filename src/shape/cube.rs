@@ -0,0 +1,167 @@
+use super::bounds::BoundingBox;
+use super::{Shape, ShapeData};
+use crate::elementary::float::Float;
+use crate::elementary::point::Point;
+use crate::elementary::vector::Vector;
+use crate::intersection::Intersection;
+use crate::ray::Ray;
+
+/// An axis-aligned cube spanning -1 to 1 on every axis in object space.
+#[derive(Debug, Default)]
+pub struct Cube {
+    data: ShapeData,
+}
+
+impl Cube {
+    pub fn new() -> Cube {
+        Cube {
+            data: ShapeData::new(),
+        }
+    }
+}
+
+/// Finds where a ray crosses the two planes bounding a slab at -1 and 1 on
+/// one axis, ordered nearest first.
+fn check_axis(origin: Float, direction: Float) -> (Float, Float) {
+    let tmin_numerator = Float::new(-1.0) - origin;
+    let tmax_numerator = Float::new(1.0) - origin;
+
+    let (tmin, tmax) = if direction.abs() >= Float::EPSILON {
+        (tmin_numerator / direction, tmax_numerator / direction)
+    } else {
+        (
+            tmin_numerator * Float::INFINITY,
+            tmax_numerator * Float::INFINITY,
+        )
+    };
+
+    if tmin > tmax {
+        return (tmax, tmin);
+    }
+
+    (tmin, tmax)
+}
+
+impl Shape for Cube {
+    fn data(&self) -> &ShapeData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut ShapeData {
+        &mut self.data
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        let (xtmin, xtmax) = check_axis(ray.origin().x(), ray.direction().x());
+        let (ytmin, ytmax) = check_axis(ray.origin().y(), ray.direction().y());
+        let (ztmin, ztmax) = check_axis(ray.origin().z(), ray.direction().z());
+
+        let tmin = xtmin.max(ytmin).max(ztmin);
+        let tmax = xtmax.min(ytmax).min(ztmax);
+
+        if tmin > tmax {
+            return vec![];
+        }
+
+        vec![Intersection::new(tmin, self), Intersection::new(tmax, self)]
+    }
+
+    fn local_normal_at(&self, point: Point, _hit: &Intersection) -> Vector {
+        let zero = Float::new(0.0);
+        let maxc = point.x().abs().max(point.y().abs()).max(point.z().abs());
+
+        if maxc == point.x().abs() {
+            return Vector::new(point.x(), zero, zero);
+        } else if maxc == point.y().abs() {
+            return Vector::new(zero, point.y(), zero);
+        }
+
+        Vector::new(zero, zero, point.z())
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(
+            Point::new(Float::new(-1.0), Float::new(-1.0), Float::new(-1.0)),
+            Point::new(Float::new(1.0), Float::new(1.0), Float::new(1.0)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod cube_tests {
+    use super::Cube;
+    use super::Float;
+    use super::Intersection;
+    use super::Ray;
+    use super::Shape;
+    use crate::test_helpers::{point, vector};
+
+    #[test]
+    fn ray_intersects_a_cube() {
+        let cube = Cube::new();
+        let cases = [
+            (point(5.0, 0.5, 0.0), vector(-1.0, 0.0, 0.0), 4.0, 6.0),
+            (point(-5.0, 0.5, 0.0), vector(1.0, 0.0, 0.0), 4.0, 6.0),
+            (point(0.5, 5.0, 0.0), vector(0.0, -1.0, 0.0), 4.0, 6.0),
+            (point(0.5, -5.0, 0.0), vector(0.0, 1.0, 0.0), 4.0, 6.0),
+            (point(0.5, 0.0, 5.0), vector(0.0, 0.0, -1.0), 4.0, 6.0),
+            (point(0.5, 0.0, -5.0), vector(0.0, 0.0, 1.0), 4.0, 6.0),
+            (point(0.0, 0.5, 0.0), vector(0.0, 0.0, 1.0), -1.0, 1.0),
+        ];
+
+        for (origin, direction, t1, t2) in cases {
+            let xs = cube.local_intersect(&Ray::new(origin, direction));
+
+            assert_eq!(xs.len(), 2);
+            assert_eq!(xs[0].t(), Float::new(t1));
+            assert_eq!(xs[1].t(), Float::new(t2));
+        }
+    }
+
+    #[test]
+    fn ray_misses_a_cube() {
+        let cube = Cube::new();
+        let cases = [
+            (point(-2.0, 0.0, 0.0), vector(0.2673, 0.5345, 0.8018)),
+            (point(0.0, -2.0, 0.0), vector(0.8018, 0.2673, 0.5345)),
+            (point(0.0, 0.0, -2.0), vector(0.5345, 0.8018, 0.2673)),
+            (point(2.0, 0.0, 2.0), vector(0.0, 0.0, -1.0)),
+            (point(0.0, 2.0, 2.0), vector(0.0, -1.0, 0.0)),
+            (point(2.0, 2.0, 0.0), vector(-1.0, 0.0, 0.0)),
+        ];
+
+        for (origin, direction) in cases {
+            assert!(cube
+                .local_intersect(&Ray::new(origin, direction))
+                .is_empty());
+        }
+    }
+
+    #[test]
+    fn normal_on_the_surface_of_a_cube() {
+        let cube = Cube::new();
+        let cases = [
+            (point(1.0, 0.5, -0.8), vector(1.0, 0.0, 0.0)),
+            (point(-1.0, -0.2, 0.9), vector(-1.0, 0.0, 0.0)),
+            (point(-0.4, 1.0, -0.1), vector(0.0, 1.0, 0.0)),
+            (point(0.3, -1.0, -0.7), vector(0.0, -1.0, 0.0)),
+            (point(-0.6, 0.3, 1.0), vector(0.0, 0.0, 1.0)),
+            (point(0.4, 0.4, -1.0), vector(0.0, 0.0, -1.0)),
+            (point(1.0, 1.0, 1.0), vector(1.0, 0.0, 0.0)),
+            (point(-1.0, -1.0, -1.0), vector(-1.0, 0.0, 0.0)),
+        ];
+        let hit = Intersection::new(Float::new(0.0), &cube);
+
+        for (point, normal) in cases {
+            assert_eq!(cube.local_normal_at(point, &hit), normal);
+        }
+    }
+
+    #[test]
+    fn a_cube_has_a_bounding_box() {
+        let bounds = Cube::new().bounds();
+
+        assert_eq!(bounds.min(), point(-1.0, -1.0, -1.0));
+        assert_eq!(bounds.max(), point(1.0, 1.0, 1.0));
+    }
+}
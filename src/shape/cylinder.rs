@@ -0,0 +1,292 @@
+use super::bounds::BoundingBox;
+use super::{Shape, ShapeData};
+use crate::elementary::float::Float;
+use crate::elementary::point::Point;
+use crate::elementary::vector::Vector;
+use crate::intersection::Intersection;
+use crate::ray::Ray;
+
+/// A cylinder of radius 1 around the object-space y axis, optionally
+/// truncated to `minimum < y < maximum` and capped at both ends.
+#[derive(Debug)]
+pub struct Cylinder {
+    data: ShapeData,
+    minimum: Float,
+    maximum: Float,
+    closed: bool,
+}
+
+impl Cylinder {
+    pub fn new() -> Cylinder {
+        Cylinder {
+            data: ShapeData::new(),
+            minimum: -Float::INFINITY,
+            maximum: Float::INFINITY,
+            closed: false,
+        }
+    }
+
+    pub fn minimum(&self) -> Float {
+        self.minimum
+    }
+
+    pub fn set_minimum(&mut self, minimum: Float) {
+        self.minimum = minimum;
+    }
+
+    pub fn maximum(&self) -> Float {
+        self.maximum
+    }
+
+    pub fn set_maximum(&mut self, maximum: Float) {
+        self.maximum = maximum;
+    }
+
+    pub fn closed(&self) -> bool {
+        self.closed
+    }
+
+    pub fn set_closed(&mut self, closed: bool) {
+        self.closed = closed;
+    }
+
+    fn intersect_caps<'a>(&'a self, ray: &Ray, xs: &mut Vec<Intersection<'a>>) {
+        // caps only matter when closed, and a ray parallel to them misses
+        if !self.closed || ray.direction().y().abs() < Float::EPSILON {
+            return;
+        }
+
+        for y in [self.minimum, self.maximum] {
+            let t = (y - ray.origin().y()) / ray.direction().y();
+            if check_cap(ray, t, Float::new(1.0)) {
+                xs.push(Intersection::new(t, self));
+            }
+        }
+    }
+}
+
+impl Default for Cylinder {
+    fn default() -> Self {
+        Cylinder::new()
+    }
+}
+
+/// Checks whether the point at `t` along the ray lies within `radius` of
+/// the y axis, i.e. inside a cap.
+pub(super) fn check_cap(ray: &Ray, t: Float, radius: Float) -> bool {
+    let x = ray.origin().x() + t * ray.direction().x();
+    let z = ray.origin().z() + t * ray.direction().z();
+
+    x * x + z * z <= radius * radius
+}
+
+impl Shape for Cylinder {
+    fn data(&self) -> &ShapeData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut ShapeData {
+        &mut self.data
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        let mut xs = vec![];
+        let (origin, direction) = (ray.origin(), ray.direction());
+
+        let a = direction.x() * direction.x() + direction.z() * direction.z();
+        // a ray parallel to the y axis can only hit the caps
+        if a.abs() >= Float::EPSILON {
+            let b = Float::new(2.0) * origin.x() * direction.x()
+                + Float::new(2.0) * origin.z() * direction.z();
+            let c = origin.x() * origin.x() + origin.z() * origin.z() - Float::new(1.0);
+            let discriminant = b * b - Float::new(4.0) * a * c;
+
+            // exact, as for spheres: no square root below 0
+            if discriminant.value() < 0.0 {
+                return xs;
+            }
+
+            let t0 = (-b - discriminant.sqrt()) / (Float::new(2.0) * a);
+            let t1 = (-b + discriminant.sqrt()) / (Float::new(2.0) * a);
+
+            for t in [t0.min(t1), t0.max(t1)] {
+                let y = origin.y() + t * direction.y();
+                if self.minimum < y && y < self.maximum {
+                    xs.push(Intersection::new(t, self));
+                }
+            }
+        }
+
+        self.intersect_caps(ray, &mut xs);
+        xs
+    }
+
+    fn local_normal_at(&self, point: Point, _hit: &Intersection) -> Vector {
+        let zero = Float::new(0.0);
+        let distance = point.x() * point.x() + point.z() * point.z();
+
+        if distance < Float::new(1.0) && point.y() >= self.maximum - Float::EPSILON {
+            return Vector::new(zero, Float::new(1.0), zero);
+        } else if distance < Float::new(1.0) && point.y() <= self.minimum + Float::EPSILON {
+            return Vector::new(zero, Float::new(-1.0), zero);
+        }
+
+        Vector::new(point.x(), zero, point.z())
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(
+            Point::new(Float::new(-1.0), self.minimum, Float::new(-1.0)),
+            Point::new(Float::new(1.0), self.maximum, Float::new(1.0)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod cylinder_tests {
+    use super::Cylinder;
+    use super::Float;
+    use super::Intersection;
+    use super::Ray;
+    use super::Shape;
+    use crate::test_helpers::{point, vector};
+
+    fn truncated(closed: bool) -> Cylinder {
+        let mut cylinder = Cylinder::new();
+        cylinder.set_minimum(Float::new(1.0));
+        cylinder.set_maximum(Float::new(2.0));
+        cylinder.set_closed(closed);
+        cylinder
+    }
+
+    #[test]
+    fn ray_misses_a_cylinder() {
+        let cylinder = Cylinder::new();
+        let cases = [
+            (point(1.0, 0.0, 0.0), vector(0.0, 1.0, 0.0)),
+            (point(0.0, 0.0, 0.0), vector(0.0, 1.0, 0.0)),
+            (point(0.0, 0.0, -5.0), vector(1.0, 1.0, 1.0)),
+            (point(1.000001, 0.0, -5.0), vector(0.0, 0.0, 1.0)),
+        ];
+
+        for (origin, direction) in cases {
+            let ray = Ray::new(origin, direction.normalize());
+            assert!(cylinder.local_intersect(&ray).is_empty());
+        }
+    }
+
+    #[test]
+    fn ray_strikes_a_cylinder() {
+        let cylinder = Cylinder::new();
+        let cases = [
+            (point(1.0, 0.0, -5.0), vector(0.0, 0.0, 1.0), 5.0, 5.0),
+            (point(0.0, 0.0, -5.0), vector(0.0, 0.0, 1.0), 4.0, 6.0),
+            (
+                point(0.5, 0.0, -5.0),
+                vector(0.1, 1.0, 1.0),
+                6.80798,
+                7.08872,
+            ),
+        ];
+
+        for (origin, direction, t0, t1) in cases {
+            let xs = cylinder.local_intersect(&Ray::new(origin, direction.normalize()));
+
+            assert_eq!(xs.len(), 2);
+            assert_eq!(xs[0].t(), Float::new(t0));
+            assert_eq!(xs[1].t(), Float::new(t1));
+        }
+    }
+
+    #[test]
+    fn normal_vector_on_a_cylinder() {
+        let cylinder = Cylinder::new();
+        let cases = [
+            (point(1.0, 0.0, 0.0), vector(1.0, 0.0, 0.0)),
+            (point(0.0, 5.0, -1.0), vector(0.0, 0.0, -1.0)),
+            (point(0.0, -2.0, 1.0), vector(0.0, 0.0, 1.0)),
+            (point(-1.0, 1.0, 0.0), vector(-1.0, 0.0, 0.0)),
+        ];
+        let hit = Intersection::new(Float::new(0.0), &cylinder);
+
+        for (point, normal) in cases {
+            assert_eq!(cylinder.local_normal_at(point, &hit), normal);
+        }
+    }
+
+    #[test]
+    fn default_cylinder_is_infinite_and_open() {
+        let cylinder = Cylinder::new();
+
+        assert_eq!(cylinder.minimum(), -Float::INFINITY);
+        assert_eq!(cylinder.maximum(), Float::INFINITY);
+        assert!(!cylinder.closed());
+    }
+
+    #[test]
+    fn intersecting_a_constrained_cylinder() {
+        let cylinder = truncated(false);
+        let cases = [
+            (point(0.0, 1.5, 0.0), vector(0.1, 1.0, 0.0), 0),
+            (point(0.0, 3.0, -5.0), vector(0.0, 0.0, 1.0), 0),
+            (point(0.0, 0.0, -5.0), vector(0.0, 0.0, 1.0), 0),
+            (point(0.0, 2.0, -5.0), vector(0.0, 0.0, 1.0), 0),
+            (point(0.0, 1.0, -5.0), vector(0.0, 0.0, 1.0), 0),
+            (point(0.0, 1.5, -2.0), vector(0.0, 0.0, 1.0), 2),
+        ];
+
+        for (origin, direction, count) in cases {
+            let ray = Ray::new(origin, direction.normalize());
+            assert_eq!(cylinder.local_intersect(&ray).len(), count);
+        }
+    }
+
+    #[test]
+    fn intersecting_the_caps_of_a_closed_cylinder() {
+        let cylinder = truncated(true);
+        let cases = [
+            (point(0.0, 3.0, 0.0), vector(0.0, -1.0, 0.0), 2),
+            (point(0.0, 3.0, -2.0), vector(0.0, -1.0, 2.0), 2),
+            (point(0.0, 4.0, -2.0), vector(0.0, -1.0, 1.0), 2),
+            (point(0.0, 0.0, -2.0), vector(0.0, 1.0, 2.0), 2),
+            (point(0.0, -1.0, -2.0), vector(0.0, 1.0, 1.0), 2),
+        ];
+
+        for (origin, direction, count) in cases {
+            let ray = Ray::new(origin, direction.normalize());
+            assert_eq!(cylinder.local_intersect(&ray).len(), count);
+        }
+    }
+
+    #[test]
+    fn normal_vector_on_the_end_caps() {
+        let cylinder = truncated(true);
+        let cases = [
+            (point(0.0, 1.0, 0.0), vector(0.0, -1.0, 0.0)),
+            (point(0.5, 1.0, 0.0), vector(0.0, -1.0, 0.0)),
+            (point(0.0, 1.0, 0.5), vector(0.0, -1.0, 0.0)),
+            (point(0.0, 2.0, 0.0), vector(0.0, 1.0, 0.0)),
+            (point(0.5, 2.0, 0.0), vector(0.0, 1.0, 0.0)),
+            (point(0.0, 2.0, 0.5), vector(0.0, 1.0, 0.0)),
+        ];
+        let hit = Intersection::new(Float::new(0.0), &cylinder);
+
+        for (point, normal) in cases {
+            assert_eq!(cylinder.local_normal_at(point, &hit), normal);
+        }
+    }
+
+    #[test]
+    fn a_cylinder_has_a_bounding_box() {
+        let unbounded = Cylinder::new().bounds();
+        let mut cylinder = Cylinder::new();
+        cylinder.set_minimum(Float::new(-5.0));
+        cylinder.set_maximum(Float::new(3.0));
+        let bounded = cylinder.bounds();
+
+        assert_eq!(unbounded.min(), point(-1.0, -f64::INFINITY, -1.0));
+        assert_eq!(unbounded.max(), point(1.0, f64::INFINITY, 1.0));
+        assert_eq!(bounded.min(), point(-1.0, -5.0, -1.0));
+        assert_eq!(bounded.max(), point(1.0, 3.0, 1.0));
+    }
+}
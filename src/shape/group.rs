@@ -0,0 +1,346 @@
+use super::bounds::BoundingBox;
+use super::bvh::Bvh;
+use super::{Shape, ShapeData};
+use crate::elementary::matrix::Matrix4;
+use crate::elementary::point::Point;
+use crate::elementary::vector::Vector;
+use crate::intersection::Intersection;
+use crate::ray::Ray;
+
+use std::cell::OnceCell;
+
+/// A collection of shapes sharing one transform. Rays are moved into the
+/// group's space before being handed to each child, so a group can be
+/// placed, nested or repeated as a single object. A bounding volume
+/// hierarchy over the children is built on first use, so only children near
+/// the ray are tested.
+#[derive(Debug, Default)]
+pub struct Group {
+    data: ShapeData,
+    children: Vec<Box<dyn Shape>>,
+    bvh: OnceCell<Bvh>,
+}
+
+impl Group {
+    pub fn new() -> Group {
+        Group {
+            data: ShapeData::new(),
+            children: vec![],
+            bvh: OnceCell::new(),
+        }
+    }
+
+    pub fn children(&self) -> &[Box<dyn Shape>] {
+        &self.children
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    pub fn add_child<S: Shape + 'static>(&mut self, child: S) {
+        self.add_boxed_child(Box::new(child));
+    }
+
+    pub fn add_boxed_child(&mut self, mut child: Box<dyn Shape>) {
+        child.set_parent_inverses(self.child_parent_inverses());
+        self.children.push(child);
+        self.bvh.take();
+    }
+
+    fn bvh(&self) -> &Bvh {
+        self.bvh.get_or_init(|| {
+            let bounds = self
+                .children
+                .iter()
+                .map(|child| child.parent_space_bounds())
+                .collect::<Vec<BoundingBox>>();
+
+            Bvh::build(&bounds)
+        })
+    }
+
+    fn child_parent_inverses(&self) -> Vec<Matrix4> {
+        let mut parent_inverses = self.parent_inverses().to_vec();
+        parent_inverses.push(*self.inverse());
+
+        parent_inverses
+    }
+}
+
+impl Shape for Group {
+    fn data(&self) -> &ShapeData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut ShapeData {
+        &mut self.data
+    }
+
+    fn set_parent_inverses(&mut self, parent_inverses: Vec<Matrix4>) {
+        self.data.parent_inverses = parent_inverses;
+
+        let child_parent_inverses = self.child_parent_inverses();
+        for child in self.children.iter_mut() {
+            child.set_parent_inverses(child_parent_inverses.clone());
+        }
+    }
+
+    fn includes(&self, other: &dyn Shape) -> bool {
+        self.children.iter().any(|child| child.includes(other))
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        self.bvh()
+            .candidates(ray)
+            .into_iter()
+            .flat_map(|index| self.children[index].intersect(ray))
+            .collect()
+    }
+
+    fn local_normal_at(&self, _point: Point, _hit: &Intersection) -> Vector {
+        unreachable!("a group has no surface of its own; normals come from its children")
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        self.bvh().bounds()
+    }
+}
+
+#[cfg(test)]
+mod group_tests {
+    use super::Group;
+    use super::Matrix4;
+    use super::Ray;
+    use super::Shape;
+    use crate::elementary::float::Float;
+    use crate::intersection::Intersection;
+    use crate::shape::cylinder::Cylinder;
+    use crate::shape::sphere::Sphere;
+    use crate::test_helpers::{downcast, point, vector};
+    use std::f64::consts::PI;
+
+    fn group(shape: &dyn Shape) -> &Group {
+        downcast::<Group>(shape).unwrap()
+    }
+
+    /// g1 rotated about y, holding g2, holding a translated sphere.
+    fn nested_groups(g2_scaling: (f64, f64, f64)) -> Group {
+        let mut sphere = Sphere::new();
+        sphere.set_transform(Matrix4::translation(
+            Float::new(5.0),
+            Float::new(0.0),
+            Float::new(0.0),
+        ));
+        let mut g2 = Group::new();
+        g2.set_transform(Matrix4::scaling(
+            Float::new(g2_scaling.0),
+            Float::new(g2_scaling.1),
+            Float::new(g2_scaling.2),
+        ));
+        g2.add_child(sphere);
+        let mut g1 = Group::new();
+        g1.add_child(g2);
+        g1.set_transform(Matrix4::rotation_y(Float::new(PI / 2.0)));
+
+        g1
+    }
+
+    #[test]
+    fn can_create_a_group() {
+        let group = Group::new();
+
+        assert_eq!(*group.transform(), Matrix4::identity());
+        assert!(group.is_empty());
+    }
+
+    #[test]
+    fn adding_a_child_to_a_group() {
+        let mut group = Group::new();
+        group.add_child(Sphere::new());
+
+        assert_eq!(group.children().len(), 1);
+        assert_eq!(group.children()[0].parent_inverses().len(), 1);
+    }
+
+    #[test]
+    fn intersecting_a_ray_with_an_empty_group() {
+        let group = Group::new();
+        let ray = Ray::new(point(0.0, 0.0, 0.0), vector(0.0, 0.0, 1.0));
+
+        assert!(group.intersect(&ray).is_empty());
+    }
+
+    #[test]
+    fn intersecting_a_ray_with_a_nonempty_group() {
+        let mut group = Group::new();
+        group.add_child(Sphere::new());
+        let mut s2 = Sphere::new();
+        s2.set_transform(Matrix4::translation(
+            Float::new(0.0),
+            Float::new(0.0),
+            Float::new(-3.0),
+        ));
+        group.add_child(s2);
+        let mut s3 = Sphere::new();
+        s3.set_transform(Matrix4::translation(
+            Float::new(5.0),
+            Float::new(0.0),
+            Float::new(0.0),
+        ));
+        group.add_child(s3);
+        let ray = Ray::new(point(0.0, 0.0, -5.0), vector(0.0, 0.0, 1.0));
+        let xs = group.intersect(&ray);
+        let (s1, s2) = (group.children()[0].as_ref(), group.children()[1].as_ref());
+
+        assert_eq!(xs.len(), 4);
+        assert!(std::ptr::addr_eq(xs[0].object(), s2));
+        assert!(std::ptr::addr_eq(xs[1].object(), s2));
+        assert!(std::ptr::addr_eq(xs[2].object(), s1));
+        assert!(std::ptr::addr_eq(xs[3].object(), s1));
+    }
+
+    #[test]
+    fn intersecting_a_transformed_group() {
+        let mut group = Group::new();
+        group.set_transform(Matrix4::scaling(
+            Float::new(2.0),
+            Float::new(2.0),
+            Float::new(2.0),
+        ));
+        let mut sphere = Sphere::new();
+        sphere.set_transform(Matrix4::translation(
+            Float::new(5.0),
+            Float::new(0.0),
+            Float::new(0.0),
+        ));
+        group.add_child(sphere);
+        let ray = Ray::new(point(10.0, 0.0, -10.0), vector(0.0, 0.0, 1.0));
+
+        assert_eq!(group.intersect(&ray).len(), 2);
+    }
+
+    #[test]
+    fn converting_a_point_from_world_to_object_space() {
+        let g1 = nested_groups((2.0, 2.0, 2.0));
+        let sphere = group(g1.children()[0].as_ref()).children()[0].as_ref();
+
+        assert_eq!(
+            sphere.world_to_object(point(-2.0, 0.0, -10.0)),
+            point(0.0, 0.0, -1.0)
+        );
+    }
+
+    #[test]
+    fn converting_a_normal_from_object_to_world_space() {
+        let g1 = nested_groups((1.0, 2.0, 3.0));
+        let sphere = group(g1.children()[0].as_ref()).children()[0].as_ref();
+        let value = 3.0_f64.sqrt() / 3.0;
+
+        assert_eq!(
+            sphere.normal_to_world(vector(value, value, value)),
+            vector(0.28571, 0.42857, -0.85714)
+        );
+    }
+
+    #[test]
+    fn finding_the_normal_on_a_child_object() {
+        let g1 = nested_groups((1.0, 2.0, 3.0));
+        let sphere = group(g1.children()[0].as_ref()).children()[0].as_ref();
+        let hit = Intersection::new(Float::new(0.0), sphere);
+
+        assert_eq!(
+            sphere.normal_at(point(1.7321, 1.1547, -5.5774), &hit),
+            vector(0.28570, 0.42854, -0.85716)
+        );
+    }
+
+    #[test]
+    fn shading_normals_follow_the_hit_through_groups() {
+        let g1 = nested_groups((1.0, 2.0, 3.0));
+        let ray = Ray::new(point(0.0, 0.0, 20.0), vector(0.0, 0.0, -1.0));
+        let xs = g1.intersect(&ray);
+        let hit = xs.hit().unwrap();
+        let comps = hit.prepare_computations(&ray);
+
+        // the sphere ends up centered on (0, 0, -5) and one unit deep in z
+        assert_eq!(xs.len(), 2);
+        assert_eq!(hit.t(), Float::new(24.0));
+        assert_eq!(comps.normalv(), vector(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn a_group_has_a_bounding_box_that_contains_its_children() {
+        let mut sphere = Sphere::new();
+        sphere.set_transform(
+            Matrix4::scaling(Float::new(2.0), Float::new(2.0), Float::new(2.0)).translate(
+                Float::new(2.0),
+                Float::new(5.0),
+                Float::new(-3.0),
+            ),
+        );
+        let mut cylinder = Cylinder::new();
+        cylinder.set_minimum(Float::new(-2.0));
+        cylinder.set_maximum(Float::new(2.0));
+        cylinder.set_transform(
+            Matrix4::scaling(Float::new(0.5), Float::new(1.0), Float::new(0.5)).translate(
+                Float::new(-4.0),
+                Float::new(-1.0),
+                Float::new(4.0),
+            ),
+        );
+        let mut group = Group::new();
+        group.add_child(sphere);
+        group.add_child(cylinder);
+        let bounds = group.bounds();
+
+        assert_eq!(bounds.min(), point(-4.5, -3.0, -5.0));
+        assert_eq!(bounds.max(), point(4.0, 7.0, 4.5));
+    }
+
+    #[test]
+    fn a_large_group_finds_the_same_hits_as_testing_every_child() {
+        let mut group = Group::new();
+        for i in 0..10 {
+            for j in 0..10 {
+                let mut sphere = Sphere::new();
+                sphere.set_transform(
+                    Matrix4::scaling(Float::new(0.4), Float::new(0.4), Float::new(0.4)).translate(
+                        Float::new(i as f64),
+                        Float::new(j as f64),
+                        Float::new(0.0),
+                    ),
+                );
+                group.add_child(sphere);
+            }
+        }
+
+        for (x, y) in [(3.0, 4.0), (0.2, 9.1), (5.5, 5.5), (-2.0, 0.0)] {
+            let ray = Ray::new(point(x, y, -5.0), vector(0.0, 0.0, 1.0));
+            let expected = group
+                .children()
+                .iter()
+                .flat_map(|child| child.intersect(&ray))
+                .count();
+
+            assert_eq!(group.intersect(&ray).len(), expected);
+        }
+    }
+
+    #[test]
+    fn adding_a_child_updates_the_bounding_box() {
+        let mut group = Group::new();
+        group.add_child(Sphere::new());
+        assert_eq!(group.bounds().max(), point(1.0, 1.0, 1.0));
+
+        let mut sphere = Sphere::new();
+        sphere.set_transform(Matrix4::translation(
+            Float::new(5.0),
+            Float::new(0.0),
+            Float::new(0.0),
+        ));
+        group.add_child(sphere);
+
+        assert_eq!(group.bounds().max(), point(6.0, 1.0, 1.0));
+    }
+}
@@ -0,0 +1,329 @@
+pub mod bounds;
+pub mod bvh;
+pub mod cone;
+pub mod csg;
+pub mod cube;
+pub mod cylinder;
+pub mod group;
+pub mod plane;
+pub mod smooth_triangle;
+pub mod sphere;
+pub mod triangle;
+
+use crate::elementary::float::Float;
+use crate::elementary::matrix::Matrix4;
+use crate::elementary::point::Point;
+use crate::elementary::vector::Vector;
+use crate::engine::material::Material;
+use crate::intersection::{Intersection, Intersections};
+use crate::ray::Ray;
+use bounds::BoundingBox;
+
+use std::any::Any;
+use std::fmt;
+
+/// The state every shape carries regardless of its geometry. The inverse of
+/// the transform is cached since every ray and normal goes through it, as are
+/// the inverses of any enclosing groups, outermost first.
+#[derive(Debug, Clone)]
+pub struct ShapeData {
+    transform: Matrix4,
+    inverse: Matrix4,
+    parent_inverses: Vec<Matrix4>,
+    material: Material,
+}
+
+impl ShapeData {
+    pub fn new() -> ShapeData {
+        ShapeData {
+            transform: Matrix4::identity(),
+            inverse: Matrix4::identity(),
+            parent_inverses: vec![],
+            material: Material::default(),
+        }
+    }
+}
+
+impl Default for ShapeData {
+    fn default() -> Self {
+        ShapeData::new()
+    }
+}
+
+/// A shape is defined in its own object space: implementors only describe
+/// how a ray meets the untransformed geometry and what the normal is there,
+/// while `intersect` and `normal_at` handle conversion from world space.
+/// `Any` lets the concrete type behind a `dyn Shape` be recovered.
+pub trait Shape: Any + fmt::Debug {
+    fn data(&self) -> &ShapeData;
+
+    fn data_mut(&mut self) -> &mut ShapeData;
+
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>>;
+
+    /// The hit being shaded is passed along for surfaces whose normal
+    /// depends on where they were struck, such as smooth triangles.
+    fn local_normal_at(&self, point: Point, hit: &Intersection) -> Vector;
+
+    /// The axis-aligned box enclosing the untransformed shape.
+    fn bounds(&self) -> BoundingBox;
+
+    /// The box enclosing the shape once its own transform is applied, which
+    /// is the space its group or the world sees it in.
+    fn parent_space_bounds(&self) -> BoundingBox {
+        self.bounds().transform(self.transform())
+    }
+
+    /// The texture coordinates a shape assigns to a point in its object
+    /// space, for shapes that carry their own such as mesh triangles. Other
+    /// shapes leave `u`/`v` to a `UvMapping`.
+    fn texture_coords_at(&self, _point: Point) -> Option<(Float, Float)> {
+        None
+    }
+
+    fn transform(&self) -> &Matrix4 {
+        &self.data().transform
+    }
+
+    fn inverse(&self) -> &Matrix4 {
+        &self.data().inverse
+    }
+
+    fn set_transform(&mut self, transform: Matrix4) {
+        let data = self.data_mut();
+        data.inverse = transform
+            .inverse()
+            .expect("shape transform must be invertible");
+        data.transform = transform;
+
+        // groups hand their new inverse down to their children
+        let parent_inverses = self.data().parent_inverses.clone();
+        self.set_parent_inverses(parent_inverses);
+    }
+
+    /// The inverse transforms of the groups this shape sits in, outermost
+    /// first. Empty for shapes placed directly in the world.
+    fn parent_inverses(&self) -> &[Matrix4] {
+        &self.data().parent_inverses
+    }
+
+    /// Called by a group when it adopts this shape or its own placement
+    /// changes. Groups override it to pass the chain on to their children.
+    fn set_parent_inverses(&mut self, parent_inverses: Vec<Matrix4>) {
+        self.data_mut().parent_inverses = parent_inverses;
+    }
+
+    /// Whether `other` is this shape or, for groups and CSG shapes, one of
+    /// the shapes inside it.
+    fn includes(&self, other: &dyn Shape) -> bool {
+        std::ptr::addr_eq(self as *const Self, other as *const dyn Shape)
+    }
+
+    fn material(&self) -> &Material {
+        &self.data().material
+    }
+
+    fn set_material(&mut self, material: Material) {
+        self.data_mut().material = material;
+    }
+
+    fn intersect(&self, ray: &Ray) -> Intersections<'_> {
+        Intersections::new(self.local_intersect(&ray.transform(self.inverse())))
+    }
+
+    /// Converts a world-space point into object space, going down through
+    /// every enclosing group first.
+    fn world_to_object(&self, world_point: Point) -> Point {
+        let group_point = self
+            .parent_inverses()
+            .iter()
+            .fold(world_point, |point, inverse| *inverse * point);
+
+        *self.inverse() * group_point
+    }
+
+    /// Converts an object-space normal into world space, going back up
+    /// through every enclosing group.
+    fn normal_to_world(&self, local_normal: Vector) -> Vector {
+        let normal = (self.inverse().transpose() * local_normal).normalize();
+
+        self.parent_inverses()
+            .iter()
+            .rev()
+            .fold(normal, |normal, inverse| {
+                (inverse.transpose() * normal).normalize()
+            })
+    }
+
+    fn normal_at(&self, world_point: Point, hit: &Intersection) -> Vector {
+        let local_point = self.world_to_object(world_point);
+        let local_normal = self.local_normal_at(local_point, hit);
+
+        self.normal_to_world(local_normal)
+    }
+}
+
+#[cfg(test)]
+mod shape_tests {
+    use super::BoundingBox;
+    use super::Intersection;
+    use super::Material;
+    use super::Matrix4;
+    use super::Point;
+    use super::Ray;
+    use super::Shape;
+    use super::ShapeData;
+    use super::Vector;
+    use crate::elementary::float::Float;
+    use crate::test_helpers::{point, vector};
+    use std::cell::RefCell;
+    use std::f64::consts::{FRAC_1_SQRT_2, PI};
+
+    #[derive(Debug, Default)]
+    struct TestShape {
+        data: ShapeData,
+        saved_ray: RefCell<Option<Ray>>,
+    }
+
+    impl Shape for TestShape {
+        fn data(&self) -> &ShapeData {
+            &self.data
+        }
+
+        fn data_mut(&mut self) -> &mut ShapeData {
+            &mut self.data
+        }
+
+        fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+            self.saved_ray.replace(Some(*ray));
+            vec![]
+        }
+
+        fn local_normal_at(&self, point: Point, _hit: &Intersection) -> Vector {
+            Vector::new(point.x(), point.y(), point.z())
+        }
+
+        fn bounds(&self) -> BoundingBox {
+            BoundingBox::new(point(-1.0, -1.0, -1.0), point(1.0, 1.0, 1.0))
+        }
+    }
+
+    #[test]
+    fn shape_has_a_default_transformation_and_material() {
+        let shape = TestShape::default();
+
+        assert_eq!(*shape.transform(), Matrix4::identity());
+        assert_eq!(*shape.material(), Material::default());
+    }
+
+    #[test]
+    fn can_assign_a_transformation_and_material() {
+        let mut shape = TestShape::default();
+        let transform = Matrix4::translation(Float::new(2.0), Float::new(3.0), Float::new(4.0));
+        let mut material = Material::default();
+        material.set_ambient(Float::new(1.0));
+        shape.set_transform(transform);
+        shape.set_material(material.clone());
+
+        assert_eq!(*shape.transform(), transform);
+        assert_eq!(*shape.inverse(), transform.inverse().unwrap());
+        assert_eq!(*shape.material(), material);
+    }
+
+    #[test]
+    fn intersecting_a_scaled_shape_with_a_ray() {
+        let ray = Ray::new(point(0.0, 0.0, -5.0), vector(0.0, 0.0, 1.0));
+        let mut shape = TestShape::default();
+        shape.set_transform(Matrix4::scaling(
+            Float::new(2.0),
+            Float::new(2.0),
+            Float::new(2.0),
+        ));
+        shape.intersect(&ray);
+        let saved_ray = shape.saved_ray.borrow().unwrap();
+
+        assert_eq!(saved_ray.origin(), point(0.0, 0.0, -2.5));
+        assert_eq!(saved_ray.direction(), vector(0.0, 0.0, 0.5));
+    }
+
+    #[test]
+    fn intersecting_a_shape_scaled_down_by_a_hundred() {
+        let ray = Ray::new(point(0.0, 0.0, -5.0), vector(0.0, 0.0, 1.0));
+        let mut shape = TestShape::default();
+        shape.set_transform(Matrix4::scaling(
+            Float::new(0.01),
+            Float::new(0.01),
+            Float::new(0.01),
+        ));
+        shape.intersect(&ray);
+        let saved_ray = shape.saved_ray.borrow().unwrap();
+
+        assert_eq!(saved_ray.origin(), point(0.0, 0.0, -500.0));
+        assert_eq!(saved_ray.direction(), vector(0.0, 0.0, 100.0));
+    }
+
+    #[test]
+    fn intersecting_a_translated_shape_with_a_ray() {
+        let ray = Ray::new(point(0.0, 0.0, -5.0), vector(0.0, 0.0, 1.0));
+        let mut shape = TestShape::default();
+        shape.set_transform(Matrix4::translation(
+            Float::new(5.0),
+            Float::new(0.0),
+            Float::new(0.0),
+        ));
+        shape.intersect(&ray);
+        let saved_ray = shape.saved_ray.borrow().unwrap();
+
+        assert_eq!(saved_ray.origin(), point(-5.0, 0.0, -5.0));
+        assert_eq!(saved_ray.direction(), vector(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn computing_the_normal_on_a_translated_shape() {
+        let mut shape = TestShape::default();
+        shape.set_transform(Matrix4::translation(
+            Float::new(0.0),
+            Float::new(1.0),
+            Float::new(0.0),
+        ));
+        let hit = Intersection::new(Float::new(0.0), &shape);
+
+        assert_eq!(
+            shape.normal_at(point(0.0, 1.0 + FRAC_1_SQRT_2, -FRAC_1_SQRT_2), &hit),
+            vector(0.0, FRAC_1_SQRT_2, -FRAC_1_SQRT_2)
+        );
+    }
+
+    #[test]
+    fn computing_the_normal_on_a_transformed_shape() {
+        let mut shape = TestShape::default();
+        shape.set_transform(Matrix4::identity().rotate_z(Float::new(PI / 5.0)).scale(
+            Float::new(1.0),
+            Float::new(0.5),
+            Float::new(1.0),
+        ));
+        let value = 2.0_f64.sqrt() / 2.0;
+        let hit = Intersection::new(Float::new(0.0), &shape);
+
+        assert_eq!(
+            shape.normal_at(point(0.0, value, -value), &hit),
+            vector(0.0, 0.97014, -0.24254)
+        );
+    }
+
+    #[test]
+    fn querying_a_shapes_bounding_box_in_its_parents_space() {
+        let mut shape = TestShape::default();
+        shape.set_transform(
+            Matrix4::scaling(Float::new(0.5), Float::new(2.0), Float::new(4.0)).translate(
+                Float::new(1.0),
+                Float::new(-3.0),
+                Float::new(5.0),
+            ),
+        );
+        let bounds = shape.parent_space_bounds();
+
+        assert_eq!(bounds.min(), point(0.5, -5.0, 1.0));
+        assert_eq!(bounds.max(), point(1.5, -1.0, 9.0));
+    }
+}
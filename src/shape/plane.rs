@@ -0,0 +1,128 @@
+use super::bounds::BoundingBox;
+use super::{Shape, ShapeData};
+use crate::elementary::float::Float;
+use crate::elementary::point::Point;
+use crate::elementary::vector::Vector;
+use crate::intersection::Intersection;
+use crate::ray::Ray;
+
+/// An infinite plane spanning x and z in object space.
+#[derive(Debug, Default)]
+pub struct Plane {
+    data: ShapeData,
+}
+
+impl Plane {
+    pub fn new() -> Plane {
+        Plane {
+            data: ShapeData::new(),
+        }
+    }
+}
+
+impl Shape for Plane {
+    fn data(&self) -> &ShapeData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut ShapeData {
+        &mut self.data
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        // a ray parallel to the plane never meets it, and a coplanar ray is
+        // treated as a miss since the plane is infinitely thin
+        if ray.direction().y().abs() < Float::EPSILON {
+            return vec![];
+        }
+
+        let t = -ray.origin().y() / ray.direction().y();
+        vec![Intersection::new(t, self)]
+    }
+
+    fn local_normal_at(&self, _point: Point, _hit: &Intersection) -> Vector {
+        Vector::new(Float::new(0.0), Float::new(1.0), Float::new(0.0))
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(
+            Point::new(-Float::INFINITY, Float::new(0.0), -Float::INFINITY),
+            Point::new(Float::INFINITY, Float::new(0.0), Float::INFINITY),
+        )
+    }
+}
+
+#[cfg(test)]
+mod plane_tests {
+    use super::Float;
+    use super::Intersection;
+    use super::Plane;
+    use super::Ray;
+    use super::Shape;
+    use crate::test_helpers::{point, vector};
+
+    #[test]
+    fn normal_of_a_plane_is_constant_everywhere() {
+        let plane = Plane::new();
+        let hit = Intersection::new(Float::new(0.0), &plane);
+
+        assert_eq!(
+            plane.local_normal_at(point(0.0, 0.0, 0.0), &hit),
+            vector(0.0, 1.0, 0.0)
+        );
+        assert_eq!(
+            plane.local_normal_at(point(10.0, 0.0, -10.0), &hit),
+            vector(0.0, 1.0, 0.0)
+        );
+        assert_eq!(
+            plane.local_normal_at(point(-5.0, 0.0, 150.0), &hit),
+            vector(0.0, 1.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn intersect_with_a_ray_parallel_to_the_plane() {
+        let plane = Plane::new();
+        let ray = Ray::new(point(0.0, 10.0, 0.0), vector(0.0, 0.0, 1.0));
+
+        assert!(plane.local_intersect(&ray).is_empty());
+    }
+
+    #[test]
+    fn intersect_with_a_coplanar_ray() {
+        let plane = Plane::new();
+        let ray = Ray::new(point(0.0, 0.0, 0.0), vector(0.0, 0.0, 1.0));
+
+        assert!(plane.local_intersect(&ray).is_empty());
+    }
+
+    #[test]
+    fn ray_intersecting_a_plane_from_above() {
+        let plane = Plane::new();
+        let ray = Ray::new(point(0.0, 1.0, 0.0), vector(0.0, -1.0, 0.0));
+        let xs = plane.local_intersect(&ray);
+
+        assert_eq!(xs.len(), 1);
+        assert_eq!(xs[0].t(), Float::new(1.0));
+        assert!(std::ptr::addr_eq(xs[0].object(), &plane));
+    }
+
+    #[test]
+    fn ray_intersecting_a_plane_from_below() {
+        let plane = Plane::new();
+        let ray = Ray::new(point(0.0, -1.0, 0.0), vector(0.0, 1.0, 0.0));
+        let xs = plane.local_intersect(&ray);
+
+        assert_eq!(xs.len(), 1);
+        assert_eq!(xs[0].t(), Float::new(1.0));
+        assert!(std::ptr::addr_eq(xs[0].object(), &plane));
+    }
+
+    #[test]
+    fn a_plane_has_a_bounding_box() {
+        let bounds = Plane::new().bounds();
+
+        assert_eq!(bounds.min(), point(-f64::INFINITY, 0.0, -f64::INFINITY));
+        assert_eq!(bounds.max(), point(f64::INFINITY, 0.0, f64::INFINITY));
+    }
+}
@@ -0,0 +1,211 @@
+use super::bounds::BoundingBox;
+use super::triangle::{barycentric, interpolate_texture_coords, intersect_triangle};
+use super::{Shape, ShapeData};
+use crate::elementary::float::Float;
+use crate::elementary::point::Point;
+use crate::elementary::vector::Vector;
+use crate::intersection::Intersection;
+use crate::ray::Ray;
+
+/// A triangle with a normal per vertex. The normal at a hit is blended from
+/// the three using the hit's barycentric coordinates, which hides the facets
+/// of a coarse mesh.
+#[derive(Debug)]
+pub struct SmoothTriangle {
+    data: ShapeData,
+    p1: Point,
+    p2: Point,
+    p3: Point,
+    n1: Vector,
+    n2: Vector,
+    n3: Vector,
+    e1: Vector,
+    e2: Vector,
+    texture_coords: Option<[(Float, Float); 3]>,
+}
+
+impl SmoothTriangle {
+    pub fn new(
+        p1: Point,
+        p2: Point,
+        p3: Point,
+        n1: Vector,
+        n2: Vector,
+        n3: Vector,
+    ) -> SmoothTriangle {
+        SmoothTriangle {
+            data: ShapeData::new(),
+            p1,
+            p2,
+            p3,
+            n1,
+            n2,
+            n3,
+            e1: p2 - p1,
+            e2: p3 - p1,
+            texture_coords: None,
+        }
+    }
+
+    pub fn p1(&self) -> Point {
+        self.p1
+    }
+
+    pub fn p2(&self) -> Point {
+        self.p2
+    }
+
+    pub fn p3(&self) -> Point {
+        self.p3
+    }
+
+    pub fn n1(&self) -> Vector {
+        self.n1
+    }
+
+    pub fn n2(&self) -> Vector {
+        self.n2
+    }
+
+    pub fn n3(&self) -> Vector {
+        self.n3
+    }
+
+    /// The `u`/`v` texture coordinates at p1, p2 and p3, as given by a mesh.
+    pub fn texture_coords(&self) -> Option<[(Float, Float); 3]> {
+        self.texture_coords
+    }
+
+    pub fn set_texture_coords(&mut self, texture_coords: [(Float, Float); 3]) {
+        self.texture_coords = Some(texture_coords);
+    }
+}
+
+impl Shape for SmoothTriangle {
+    fn data(&self) -> &ShapeData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut ShapeData {
+        &mut self.data
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        match intersect_triangle(self.p1, self.e1, self.e2, ray) {
+            Some((t, u, v)) => vec![Intersection::with_uv(t, self, u, v)],
+            None => vec![],
+        }
+    }
+
+    fn local_normal_at(&self, _point: Point, hit: &Intersection) -> Vector {
+        self.n2 * hit.u() + self.n3 * hit.v() + self.n1 * (Float::new(1.0) - hit.u() - hit.v())
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        let mut bounds = BoundingBox::empty();
+        bounds.add_point(self.p1);
+        bounds.add_point(self.p2);
+        bounds.add_point(self.p3);
+
+        bounds
+    }
+
+    fn texture_coords_at(&self, point: Point) -> Option<(Float, Float)> {
+        let (u, v) = barycentric(self.p1, self.e1, self.e2, point);
+
+        Some(interpolate_texture_coords(self.texture_coords?, u, v))
+    }
+}
+
+#[cfg(test)]
+mod smooth_triangle_tests {
+    use super::Float;
+    use super::Intersection;
+    use super::Ray;
+    use super::Shape;
+    use super::SmoothTriangle;
+    use crate::intersection::Intersections;
+    use crate::test_helpers::{point, vector};
+
+    fn smooth_triangle() -> SmoothTriangle {
+        SmoothTriangle::new(
+            point(0.0, 1.0, 0.0),
+            point(-1.0, 0.0, 0.0),
+            point(1.0, 0.0, 0.0),
+            vector(0.0, 1.0, 0.0),
+            vector(-1.0, 0.0, 0.0),
+            vector(1.0, 0.0, 0.0),
+        )
+    }
+
+    #[test]
+    fn can_construct_a_smooth_triangle() {
+        let triangle = smooth_triangle();
+
+        assert_eq!(triangle.p1(), point(0.0, 1.0, 0.0));
+        assert_eq!(triangle.p2(), point(-1.0, 0.0, 0.0));
+        assert_eq!(triangle.p3(), point(1.0, 0.0, 0.0));
+        assert_eq!(triangle.n1(), vector(0.0, 1.0, 0.0));
+        assert_eq!(triangle.n2(), vector(-1.0, 0.0, 0.0));
+        assert_eq!(triangle.n3(), vector(1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn intersection_with_a_smooth_triangle_stores_u_and_v() {
+        let triangle = smooth_triangle();
+        let ray = Ray::new(point(-0.2, 0.3, -2.0), vector(0.0, 0.0, 1.0));
+        let xs = triangle.local_intersect(&ray);
+
+        assert_eq!(xs[0].u(), Float::new(0.45));
+        assert_eq!(xs[0].v(), Float::new(0.25));
+    }
+
+    #[test]
+    fn smooth_triangle_uses_u_and_v_to_interpolate_the_normal() {
+        let triangle = smooth_triangle();
+        let hit = Intersection::with_uv(
+            Float::new(1.0),
+            &triangle,
+            Float::new(0.45),
+            Float::new(0.25),
+        );
+
+        assert_eq!(
+            triangle.normal_at(point(0.0, 0.0, 0.0), &hit),
+            vector(-0.5547, 0.83205, 0.0)
+        );
+    }
+
+    #[test]
+    fn preparing_the_normal_on_a_smooth_triangle() {
+        let triangle = smooth_triangle();
+        let hit = Intersection::with_uv(
+            Float::new(1.0),
+            &triangle,
+            Float::new(0.45),
+            Float::new(0.25),
+        );
+        let ray = Ray::new(point(-0.2, 0.3, -2.0), vector(0.0, 0.0, 1.0));
+        let xs = Intersections::new(vec![hit]);
+        let comps = xs[0].prepare_computations(&ray);
+
+        assert_eq!(comps.normalv(), vector(-0.5547, 0.83205, 0.0));
+    }
+
+    #[test]
+    fn texture_coordinates_are_interpolated_across_a_smooth_triangle() {
+        let mut triangle = smooth_triangle();
+        assert_eq!(triangle.texture_coords_at(point(0.0, 0.5, 0.0)), None);
+
+        triangle.set_texture_coords([
+            (Float::new(0.5), Float::new(1.0)),
+            (Float::new(0.0), Float::new(0.0)),
+            (Float::new(1.0), Float::new(0.0)),
+        ]);
+
+        assert_eq!(
+            triangle.texture_coords_at(point(-0.2, 0.3, 0.0)),
+            Some((Float::new(0.4), Float::new(0.3)))
+        );
+    }
+}
@@ -0,0 +1,301 @@
+use super::bounds::BoundingBox;
+use super::{Shape, ShapeData};
+use crate::elementary::float::Float;
+use crate::elementary::point::Point;
+use crate::elementary::vector::Vector;
+use crate::intersection::Intersection;
+use crate::ray::Ray;
+
+/// A unit sphere centered at the object-space origin.
+#[derive(Debug, Default)]
+pub struct Sphere {
+    data: ShapeData,
+}
+
+impl Sphere {
+    pub fn new() -> Sphere {
+        Sphere {
+            data: ShapeData::new(),
+        }
+    }
+}
+
+impl Shape for Sphere {
+    fn data(&self) -> &ShapeData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut ShapeData {
+        &mut self.data
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        let sphere_to_ray =
+            ray.origin() - Point::new(Float::new(0.0), Float::new(0.0), Float::new(0.0));
+
+        let a = ray.direction().dot(ray.direction());
+        let b = Float::new(2.0) * ray.direction().dot(sphere_to_ray);
+        let c = sphere_to_ray.dot(sphere_to_ray) - Float::new(1.0);
+
+        let discriminant = b * b - Float::new(4.0) * a * c;
+        // exact: a slightly negative discriminant equals 0 within tolerance
+        // but has no square root
+        if discriminant.value() < 0.0 {
+            return vec![];
+        }
+
+        let t1 = (-b - discriminant.sqrt()) / (Float::new(2.0) * a);
+        let t2 = (-b + discriminant.sqrt()) / (Float::new(2.0) * a);
+
+        vec![Intersection::new(t1, self), Intersection::new(t2, self)]
+    }
+
+    fn local_normal_at(&self, point: Point, _hit: &Intersection) -> Vector {
+        point - Point::new(Float::new(0.0), Float::new(0.0), Float::new(0.0))
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(
+            Point::new(Float::new(-1.0), Float::new(-1.0), Float::new(-1.0)),
+            Point::new(Float::new(1.0), Float::new(1.0), Float::new(1.0)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod sphere_tests {
+    use super::Float;
+    use super::Intersection;
+    use super::Point;
+    use super::Ray;
+    use super::Shape;
+    use super::Sphere;
+    use super::Vector;
+    use crate::elementary::matrix::Matrix4;
+    use crate::engine::material::Material;
+    use crate::test_helpers::{point, vector};
+    use std::f64::consts::{FRAC_1_SQRT_2, PI};
+
+    #[test]
+    fn ray_intersects_a_sphere_at_two_points() {
+        let ray = Ray::new(
+            Point::new(Float::new(0.0), Float::new(0.0), Float::new(-5.0)),
+            Vector::new(Float::new(0.0), Float::new(0.0), Float::new(1.0)),
+        );
+        let sphere = Sphere::new();
+        let xs = sphere.intersect(&ray);
+
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t(), Float::new(4.0));
+        assert_eq!(xs[1].t(), Float::new(6.0));
+    }
+
+    #[test]
+    fn ray_intersects_a_sphere_at_a_tangent() {
+        let ray = Ray::new(
+            Point::new(Float::new(0.0), Float::new(1.0), Float::new(-5.0)),
+            Vector::new(Float::new(0.0), Float::new(0.0), Float::new(1.0)),
+        );
+        let sphere = Sphere::new();
+        let xs = sphere.intersect(&ray);
+
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t(), Float::new(5.0));
+        assert_eq!(xs[1].t(), Float::new(5.0));
+    }
+
+    #[test]
+    fn ray_misses_a_sphere() {
+        let ray = Ray::new(
+            Point::new(Float::new(0.0), Float::new(2.0), Float::new(-5.0)),
+            Vector::new(Float::new(0.0), Float::new(0.0), Float::new(1.0)),
+        );
+        let sphere = Sphere::new();
+        let xs = sphere.intersect(&ray);
+
+        assert!(xs.is_empty());
+    }
+
+    #[test]
+    fn ray_just_misses_a_sphere() {
+        let ray = Ray::new(point(0.0, 1.000001, -5.0), vector(0.0, 0.0, 1.0));
+        let sphere = Sphere::new();
+
+        assert!(sphere.intersect(&ray).is_empty());
+    }
+
+    #[test]
+    fn ray_originates_inside_a_sphere() {
+        let ray = Ray::new(
+            Point::new(Float::new(0.0), Float::new(0.0), Float::new(0.0)),
+            Vector::new(Float::new(0.0), Float::new(0.0), Float::new(1.0)),
+        );
+        let sphere = Sphere::new();
+        let xs = sphere.intersect(&ray);
+
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t(), Float::new(-1.0));
+        assert_eq!(xs[1].t(), Float::new(1.0));
+    }
+
+    #[test]
+    fn sphere_is_behind_a_ray() {
+        let ray = Ray::new(
+            Point::new(Float::new(0.0), Float::new(0.0), Float::new(5.0)),
+            Vector::new(Float::new(0.0), Float::new(0.0), Float::new(1.0)),
+        );
+        let sphere = Sphere::new();
+        let xs = sphere.intersect(&ray);
+
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t(), Float::new(-6.0));
+        assert_eq!(xs[1].t(), Float::new(-4.0));
+    }
+
+    #[test]
+    fn intersect_sets_the_object_on_the_intersection() {
+        let ray = Ray::new(
+            Point::new(Float::new(0.0), Float::new(0.0), Float::new(-5.0)),
+            Vector::new(Float::new(0.0), Float::new(0.0), Float::new(1.0)),
+        );
+        let sphere = Sphere::new();
+        let xs = sphere.intersect(&ray);
+
+        assert_eq!(xs.len(), 2);
+        assert!(std::ptr::addr_eq(xs[0].object(), &sphere));
+        assert!(std::ptr::addr_eq(xs[1].object(), &sphere));
+    }
+
+    #[test]
+    fn sphere_has_a_default_transformation() {
+        let sphere = Sphere::new();
+
+        assert_eq!(*sphere.transform(), Matrix4::identity());
+    }
+
+    #[test]
+    fn can_change_the_transformation_of_a_sphere() {
+        let mut sphere = Sphere::new();
+        let transform = Matrix4::translation(Float::new(2.0), Float::new(3.0), Float::new(4.0));
+        sphere.set_transform(transform);
+
+        assert_eq!(*sphere.transform(), transform);
+    }
+
+    #[test]
+    fn can_intersect_a_scaled_sphere() {
+        let ray = Ray::new(point(0.0, 0.0, -5.0), vector(0.0, 0.0, 1.0));
+        let mut sphere = Sphere::new();
+        sphere.set_transform(Matrix4::scaling(
+            Float::new(2.0),
+            Float::new(2.0),
+            Float::new(2.0),
+        ));
+        let xs = sphere.intersect(&ray);
+
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t(), Float::new(3.0));
+        assert_eq!(xs[1].t(), Float::new(7.0));
+    }
+
+    #[test]
+    fn can_intersect_a_translated_sphere() {
+        let ray = Ray::new(point(0.0, 0.0, -5.0), vector(0.0, 0.0, 1.0));
+        let mut sphere = Sphere::new();
+        sphere.set_transform(Matrix4::translation(
+            Float::new(5.0),
+            Float::new(0.0),
+            Float::new(0.0),
+        ));
+
+        assert!(sphere.intersect(&ray).is_empty());
+    }
+
+    #[test]
+    fn can_compute_the_normal_on_the_axes() {
+        let sphere = Sphere::new();
+        let hit = Intersection::new(Float::new(0.0), &sphere);
+
+        assert_eq!(
+            sphere.normal_at(point(1.0, 0.0, 0.0), &hit),
+            vector(1.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            sphere.normal_at(point(0.0, 1.0, 0.0), &hit),
+            vector(0.0, 1.0, 0.0)
+        );
+        assert_eq!(
+            sphere.normal_at(point(0.0, 0.0, 1.0), &hit),
+            vector(0.0, 0.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn can_compute_the_normal_at_a_nonaxial_point() {
+        let sphere = Sphere::new();
+        let value = 3.0_f64.sqrt() / 3.0;
+        let hit = Intersection::new(Float::new(0.0), &sphere);
+        let normal = sphere.normal_at(point(value, value, value), &hit);
+
+        assert_eq!(normal, vector(value, value, value));
+        assert_eq!(normal, normal.normalize());
+    }
+
+    #[test]
+    fn can_compute_the_normal_on_a_translated_sphere() {
+        let mut sphere = Sphere::new();
+        sphere.set_transform(Matrix4::translation(
+            Float::new(0.0),
+            Float::new(1.0),
+            Float::new(0.0),
+        ));
+        let hit = Intersection::new(Float::new(0.0), &sphere);
+
+        assert_eq!(
+            sphere.normal_at(point(0.0, 1.0 + FRAC_1_SQRT_2, -FRAC_1_SQRT_2), &hit),
+            vector(0.0, FRAC_1_SQRT_2, -FRAC_1_SQRT_2)
+        );
+    }
+
+    #[test]
+    fn can_compute_the_normal_on_a_transformed_sphere() {
+        let mut sphere = Sphere::new();
+        sphere.set_transform(Matrix4::identity().rotate_z(Float::new(PI / 5.0)).scale(
+            Float::new(1.0),
+            Float::new(0.5),
+            Float::new(1.0),
+        ));
+        let value = 2.0_f64.sqrt() / 2.0;
+        let hit = Intersection::new(Float::new(0.0), &sphere);
+
+        assert_eq!(
+            sphere.normal_at(point(0.0, value, -value), &hit),
+            vector(0.0, 0.97014, -0.24254)
+        );
+    }
+
+    #[test]
+    fn sphere_has_a_default_material() {
+        let sphere = Sphere::new();
+
+        assert_eq!(*sphere.material(), Material::default());
+    }
+
+    #[test]
+    fn can_assign_a_material_to_a_sphere() {
+        let mut sphere = Sphere::new();
+        let mut material = Material::default();
+        material.set_ambient(Float::new(1.0));
+        sphere.set_material(material.clone());
+
+        assert_eq!(*sphere.material(), material);
+    }
+
+    #[test]
+    fn a_sphere_has_a_bounding_box() {
+        let bounds = Sphere::new().bounds();
+
+        assert_eq!(bounds.min(), point(-1.0, -1.0, -1.0));
+        assert_eq!(bounds.max(), point(1.0, 1.0, 1.0));
+    }
+}
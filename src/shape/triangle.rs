@@ -0,0 +1,302 @@
+use super::bounds::BoundingBox;
+use super::{Shape, ShapeData};
+use crate::elementary::float::Float;
+use crate::elementary::point::Point;
+use crate::elementary::vector::Vector;
+use crate::intersection::Intersection;
+use crate::ray::Ray;
+
+/// A flat triangle. The edges and normal are precomputed since meshes
+/// intersect the same triangle many times per frame.
+#[derive(Debug)]
+pub struct Triangle {
+    data: ShapeData,
+    p1: Point,
+    p2: Point,
+    p3: Point,
+    e1: Vector,
+    e2: Vector,
+    normal: Vector,
+    texture_coords: Option<[(Float, Float); 3]>,
+}
+
+impl Triangle {
+    pub fn new(p1: Point, p2: Point, p3: Point) -> Triangle {
+        let e1 = p2 - p1;
+        let e2 = p3 - p1;
+
+        Triangle {
+            data: ShapeData::new(),
+            p1,
+            p2,
+            p3,
+            e1,
+            e2,
+            normal: (e2 * e1).normalize(),
+            texture_coords: None,
+        }
+    }
+
+    pub fn p1(&self) -> Point {
+        self.p1
+    }
+
+    pub fn p2(&self) -> Point {
+        self.p2
+    }
+
+    pub fn p3(&self) -> Point {
+        self.p3
+    }
+
+    pub fn e1(&self) -> Vector {
+        self.e1
+    }
+
+    pub fn e2(&self) -> Vector {
+        self.e2
+    }
+
+    pub fn normal(&self) -> Vector {
+        self.normal
+    }
+
+    /// The `u`/`v` texture coordinates at p1, p2 and p3, as given by a mesh.
+    pub fn texture_coords(&self) -> Option<[(Float, Float); 3]> {
+        self.texture_coords
+    }
+
+    pub fn set_texture_coords(&mut self, texture_coords: [(Float, Float); 3]) {
+        self.texture_coords = Some(texture_coords);
+    }
+}
+
+/// Möller–Trumbore ray/triangle intersection, returning the distance along
+/// the ray and the barycentric `u`/`v` of the hit.
+pub(super) fn intersect_triangle(
+    p1: Point,
+    e1: Vector,
+    e2: Vector,
+    ray: &Ray,
+) -> Option<(Float, Float, Float)> {
+    let dir_cross_e2 = ray.direction() * e2;
+    let determinant = e1.dot(dir_cross_e2);
+    // the ray is parallel to the triangle's plane, measured against the edge
+    // and direction lengths so that tiny mesh triangles are still hit
+    let scale = e1.magnitude() * e2.magnitude() * ray.direction().magnitude();
+    if determinant.abs().value() < Float::EPSILON.value() * scale.value() {
+        return None;
+    }
+
+    let f = Float::new(1.0) / determinant;
+    let p1_to_origin = ray.origin() - p1;
+    let u = f * p1_to_origin.dot(dir_cross_e2);
+    if u < Float::new(0.0) || u > Float::new(1.0) {
+        return None;
+    }
+
+    let origin_cross_e1 = p1_to_origin * e1;
+    let v = f * ray.direction().dot(origin_cross_e1);
+    if v < Float::new(0.0) || u + v > Float::new(1.0) {
+        return None;
+    }
+
+    Some((f * e2.dot(origin_cross_e1), u, v))
+}
+
+/// The barycentric `u`/`v` of a point in a triangle's plane, weighting p2
+/// and p3 the same way `intersect_triangle` does.
+pub(super) fn barycentric(p1: Point, e1: Vector, e2: Vector, point: Point) -> (Float, Float) {
+    let p1_to_point = point - p1;
+    let (d11, d12, d22) = (e1.dot(e1), e1.dot(e2), e2.dot(e2));
+    let (d1p, d2p) = (e1.dot(p1_to_point), e2.dot(p1_to_point));
+    let denominator = d11 * d22 - d12 * d12;
+
+    (
+        (d22 * d1p - d12 * d2p) / denominator,
+        (d11 * d2p - d12 * d1p) / denominator,
+    )
+}
+
+/// Blends the texture coordinates at the three corners by barycentric
+/// weights.
+pub(super) fn interpolate_texture_coords(
+    texture_coords: [(Float, Float); 3],
+    u: Float,
+    v: Float,
+) -> (Float, Float) {
+    let [(u1, v1), (u2, v2), (u3, v3)] = texture_coords;
+    let w = Float::new(1.0) - u - v;
+
+    (u1 * w + u2 * u + u3 * v, v1 * w + v2 * u + v3 * v)
+}
+
+impl Shape for Triangle {
+    fn data(&self) -> &ShapeData {
+        &self.data
+    }
+
+    fn data_mut(&mut self) -> &mut ShapeData {
+        &mut self.data
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        match intersect_triangle(self.p1, self.e1, self.e2, ray) {
+            Some((t, u, v)) => vec![Intersection::with_uv(t, self, u, v)],
+            None => vec![],
+        }
+    }
+
+    fn local_normal_at(&self, _point: Point, _hit: &Intersection) -> Vector {
+        self.normal
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        let mut bounds = BoundingBox::empty();
+        bounds.add_point(self.p1);
+        bounds.add_point(self.p2);
+        bounds.add_point(self.p3);
+
+        bounds
+    }
+
+    fn texture_coords_at(&self, point: Point) -> Option<(Float, Float)> {
+        let (u, v) = barycentric(self.p1, self.e1, self.e2, point);
+
+        Some(interpolate_texture_coords(self.texture_coords?, u, v))
+    }
+}
+
+#[cfg(test)]
+mod triangle_tests {
+    use super::Float;
+    use super::Intersection;
+    use super::Ray;
+    use super::Shape;
+    use super::Triangle;
+    use crate::test_helpers::{point, vector};
+
+    fn triangle() -> Triangle {
+        Triangle::new(
+            point(0.0, 1.0, 0.0),
+            point(-1.0, 0.0, 0.0),
+            point(1.0, 0.0, 0.0),
+        )
+    }
+
+    #[test]
+    fn can_construct_a_triangle() {
+        let triangle = triangle();
+
+        assert_eq!(triangle.p1(), point(0.0, 1.0, 0.0));
+        assert_eq!(triangle.p2(), point(-1.0, 0.0, 0.0));
+        assert_eq!(triangle.p3(), point(1.0, 0.0, 0.0));
+        assert_eq!(triangle.e1(), vector(-1.0, -1.0, 0.0));
+        assert_eq!(triangle.e2(), vector(1.0, -1.0, 0.0));
+        assert_eq!(triangle.normal(), vector(0.0, 0.0, -1.0));
+    }
+
+    #[test]
+    fn normal_of_a_triangle_is_constant() {
+        let triangle = triangle();
+        let hit = Intersection::new(Float::new(0.0), &triangle);
+
+        for p in [
+            point(0.0, 0.5, 0.0),
+            point(-0.5, 0.75, 0.0),
+            point(0.5, 0.25, 0.0),
+        ] {
+            assert_eq!(triangle.local_normal_at(p, &hit), triangle.normal());
+        }
+    }
+
+    #[test]
+    fn ray_parallel_to_a_triangle_misses() {
+        let triangle = triangle();
+        let ray = Ray::new(point(0.0, -1.0, -2.0), vector(0.0, 1.0, 0.0));
+
+        assert!(triangle.local_intersect(&ray).is_empty());
+    }
+
+    #[test]
+    fn ray_misses_each_edge_of_a_triangle() {
+        let triangle = triangle();
+
+        for origin in [
+            point(1.0, 1.0, -2.0),
+            point(-1.0, 1.0, -2.0),
+            point(0.0, -1.0, -2.0),
+        ] {
+            let ray = Ray::new(origin, vector(0.0, 0.0, 1.0));
+            assert!(triangle.local_intersect(&ray).is_empty());
+        }
+    }
+
+    #[test]
+    fn ray_strikes_a_triangle() {
+        let triangle = triangle();
+        let ray = Ray::new(point(0.0, 0.5, -2.0), vector(0.0, 0.0, 1.0));
+        let xs = triangle.local_intersect(&ray);
+
+        assert_eq!(xs.len(), 1);
+        assert_eq!(xs[0].t(), Float::new(2.0));
+    }
+
+    #[test]
+    fn ray_strikes_a_tiny_triangle() {
+        let triangle = Triangle::new(
+            point(0.0, 0.001, 0.0),
+            point(-0.001, 0.0, 0.0),
+            point(0.001, 0.0, 0.0),
+        );
+        let ray = Ray::new(point(0.0, 0.0005, -2.0), vector(0.0, 0.0, 1.0));
+        let xs = triangle.local_intersect(&ray);
+
+        assert_eq!(xs.len(), 1);
+        assert_eq!(xs[0].t(), Float::new(2.0));
+    }
+
+    #[test]
+    fn a_triangle_has_a_bounding_box() {
+        let triangle = Triangle::new(
+            point(-3.0, 7.0, 2.0),
+            point(6.0, 2.0, -4.0),
+            point(2.0, -1.0, -1.0),
+        );
+        let bounds = triangle.bounds();
+
+        assert_eq!(bounds.min(), point(-3.0, -1.0, -4.0));
+        assert_eq!(bounds.max(), point(6.0, 7.0, 2.0));
+    }
+
+    #[test]
+    fn a_triangle_has_no_texture_coordinates_by_default() {
+        let triangle = triangle();
+
+        assert_eq!(triangle.texture_coords(), None);
+        assert_eq!(triangle.texture_coords_at(point(0.0, 0.5, 0.0)), None);
+    }
+
+    #[test]
+    fn texture_coordinates_are_interpolated_across_a_triangle() {
+        let mut triangle = triangle();
+        triangle.set_texture_coords([
+            (Float::new(0.5), Float::new(1.0)),
+            (Float::new(0.0), Float::new(0.0)),
+            (Float::new(1.0), Float::new(0.0)),
+        ]);
+        let uv = |u: f64, v: f64| Some((Float::new(u), Float::new(v)));
+
+        let cases = vec![
+            (point(0.0, 1.0, 0.0), uv(0.5, 1.0)),
+            (point(-1.0, 0.0, 0.0), uv(0.0, 0.0)),
+            (point(1.0, 0.0, 0.0), uv(1.0, 0.0)),
+            (point(0.0, 0.0, 0.0), uv(0.5, 0.0)),
+            (point(-0.2, 0.3, 0.0), uv(0.4, 0.3)),
+        ];
+
+        for (p, expected) in cases {
+            assert_eq!(triangle.texture_coords_at(p), expected);
+        }
+    }
+}
@@ -0,0 +1,27 @@
+//! Shorthands for building the values tests compare against from plain
+//! `f64` literals.
+
+use crate::elementary::float::Float;
+use crate::elementary::point::Point;
+use crate::elementary::vector::Vector;
+use crate::engine::color::Color;
+use crate::shape::Shape;
+
+use std::any::Any;
+
+pub fn point(x: f64, y: f64, z: f64) -> Point {
+    Point::new(Float::new(x), Float::new(y), Float::new(z))
+}
+
+pub fn vector(x: f64, y: f64, z: f64) -> Vector {
+    Vector::new(Float::new(x), Float::new(y), Float::new(z))
+}
+
+pub fn color(r: f64, g: f64, b: f64) -> Color {
+    Color::new(Float::new(r), Float::new(g), Float::new(b))
+}
+
+/// Recovers the concrete type of a shape for tests that inspect it.
+pub fn downcast<T: Shape>(shape: &dyn Shape) -> Option<&T> {
+    (shape as &dyn Any).downcast_ref::<T>()
+}